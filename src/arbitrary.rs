@@ -0,0 +1,46 @@
+use crate::{Eui, Eui48, Eui64};
+use ::arbitrary::{Arbitrary, Result, Unstructured};
+
+impl<'a> Arbitrary<'a> for Eui48 {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Eui(u.arbitrary()?))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <[u8; 6] as Arbitrary>::size_hint(depth)
+    }
+}
+
+impl<'a> Arbitrary<'a> for Eui64 {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Eui(u.arbitrary()?))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <[u8; 8] as Arbitrary>::size_hint(depth)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Eui48, Eui64};
+    use ::arbitrary::{Arbitrary, Unstructured};
+
+    #[test]
+    fn test_eui48_arbitrary() {
+        let mut u = Unstructured::new(&[0x4d, 0x7e, 0x54, 0x97, 0x2e, 0xef]);
+
+        assert_eq!(Eui48::arbitrary(&mut u), Ok(Eui48::from(85204980412143)));
+        assert_eq!(Eui48::size_hint(0), (6, Some(6)));
+    }
+
+    #[test]
+    fn test_eui64_arbitrary() {
+        let mut u = Unstructured::new(&[0x4d, 0x7e, 0x54, 0x00, 0x00, 0x97, 0x2e, 0xef]);
+
+        assert_eq!(
+            Eui64::arbitrary(&mut u),
+            Ok(Eui64::from(5583992946972634863))
+        );
+    }
+}
@@ -0,0 +1,265 @@
+use crate::Eui48Mask;
+use crate::{Eui48, Eui64};
+use core::ops::{
+    Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not, Sub,
+    SubAssign,
+};
+
+macro_rules! bitwise {
+    ($name: ident, $trait: ident, $method: ident, $assign_trait: ident, $assign_method: ident) => {
+        impl $trait for $name {
+            type Output = $name;
+
+            fn $method(mut self, rhs: $name) -> Self::Output {
+                self.$assign_method(rhs);
+                self
+            }
+        }
+
+        impl $assign_trait for $name {
+            fn $assign_method(&mut self, rhs: $name) {
+                for (octet, rhs) in self.0.iter_mut().zip(rhs.0.iter()) {
+                    octet.$assign_method(rhs);
+                }
+            }
+        }
+    };
+}
+
+macro_rules! arithmetic {
+    ($name: ident, $max: expr) => {
+        impl $name {
+            /// Largest value as integer.
+            const MAX_VALUE: u64 = $max;
+
+            /// Adds `rhs` treating eui as big-endian integer, `None` on overflow.
+            #[inline]
+            pub fn checked_add(self, rhs: u64) -> Option<Self> {
+                u64::from(self)
+                    .checked_add(rhs)
+                    .filter(|&value| value <= Self::MAX_VALUE)
+                    .map($name::from)
+            }
+
+            /// Subtracts `rhs` treating eui as big-endian integer, `None` on underflow.
+            #[inline]
+            pub fn checked_sub(self, rhs: u64) -> Option<Self> {
+                u64::from(self).checked_sub(rhs).map($name::from)
+            }
+
+            /// Returns next eui, `None` for the last one.
+            #[inline]
+            pub fn successor(self) -> Option<Self> {
+                self.checked_add(1)
+            }
+
+            /// Returns previous eui, `None` for the first one.
+            #[inline]
+            pub fn predecessor(self) -> Option<Self> {
+                self.checked_sub(1)
+            }
+
+            /// Adds `rhs` wrapping around at the end of address space.
+            #[inline]
+            pub fn wrapping_add(self, rhs: u64) -> Self {
+                $name::from(u64::from(self).wrapping_add(rhs) & Self::MAX_VALUE)
+            }
+
+            /// Subtracts `rhs` wrapping around at the start of address space.
+            #[inline]
+            pub fn wrapping_sub(self, rhs: u64) -> Self {
+                $name::from(u64::from(self).wrapping_sub(rhs) & Self::MAX_VALUE)
+            }
+
+            /// Adds `rhs` stopping at the last eui.
+            #[inline]
+            pub fn saturating_add(self, rhs: u64) -> Self {
+                self.checked_add(rhs)
+                    .unwrap_or_else(|| $name::from(Self::MAX_VALUE))
+            }
+
+            /// Subtracts `rhs` stopping at the first eui.
+            #[inline]
+            pub fn saturating_sub(self, rhs: u64) -> Self {
+                $name::from(u64::from(self).saturating_sub(rhs))
+            }
+
+            /// Returns absolute difference between euis treated as big-endian integers.
+            #[inline]
+            pub fn distance(&self, other: &$name) -> u64 {
+                u64::from(*self).abs_diff(u64::from(*other))
+            }
+        }
+
+        /// Panics on overflow, use `checked_add`, `wrapping_add` or `saturating_add` to handle it.
+        impl Add<u64> for $name {
+            type Output = $name;
+
+            fn add(self, rhs: u64) -> Self::Output {
+                self.checked_add(rhs).expect("eui addition overflowed")
+            }
+        }
+
+        /// Panics on underflow, use `checked_sub`, `wrapping_sub` or `saturating_sub` to handle it.
+        impl Sub<u64> for $name {
+            type Output = $name;
+
+            fn sub(self, rhs: u64) -> Self::Output {
+                self.checked_sub(rhs).expect("eui subtraction underflowed")
+            }
+        }
+
+        impl AddAssign<u64> for $name {
+            fn add_assign(&mut self, rhs: u64) {
+                *self = *self + rhs;
+            }
+        }
+
+        impl SubAssign<u64> for $name {
+            fn sub_assign(&mut self, rhs: u64) {
+                *self = *self - rhs;
+            }
+        }
+
+        bitwise!($name, BitAnd, bitand, BitAndAssign, bitand_assign);
+        bitwise!($name, BitOr, bitor, BitOrAssign, bitor_assign);
+        bitwise!($name, BitXor, bitxor, BitXorAssign, bitxor_assign);
+
+        impl Not for $name {
+            type Output = $name;
+
+            fn not(mut self) -> Self::Output {
+                for octet in self.0.iter_mut() {
+                    *octet = !*octet;
+                }
+
+                self
+            }
+        }
+    };
+}
+
+arithmetic!(Eui48, 0xFFFF_FFFF_FFFF);
+arithmetic!(Eui64, u64::MAX);
+
+/// Clears bits outside of mask.
+impl BitAnd<Eui48Mask> for Eui48 {
+    type Output = Eui48;
+
+    fn bitand(self, rhs: Eui48Mask) -> Self::Output {
+        self & rhs.mask()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Eui48, Eui48Mask, Eui64};
+
+    #[test]
+    fn test_eui48_checked_arithmetic() {
+        let eui48 = Eui48::from(0x4D7E_5497_2EFF);
+
+        assert_eq!(eui48.checked_add(1), Some(Eui48::from(0x4D7E_5497_2F00)));
+        assert_eq!(
+            eui48.checked_sub(0x100),
+            Some(Eui48::from(0x4D7E_5497_2DFF))
+        );
+        assert_eq!(eui48.successor(), Some(Eui48::from(0x4D7E_5497_2F00)));
+        assert_eq!(eui48.predecessor(), Some(Eui48::from(0x4D7E_5497_2EFE)));
+
+        assert_eq!(Eui48::from(0xFFFF_FFFF_FFFF).successor(), None);
+        assert_eq!(Eui48::from(0).predecessor(), None);
+        assert_eq!(Eui48::from(0).checked_add(1 << 48), None);
+        assert_eq!(Eui48::from(1).checked_add(u64::MAX), None);
+    }
+
+    #[test]
+    fn test_eui64_checked_arithmetic() {
+        assert_eq!(Eui64::from(1).checked_add(1), Some(Eui64::from(2)));
+        assert_eq!(Eui64::from(u64::MAX).successor(), None);
+        assert_eq!(Eui64::from(0).predecessor(), None);
+        assert_eq!(
+            Eui64::from(0).checked_add(u64::MAX),
+            Some(Eui64::from(u64::MAX))
+        );
+    }
+
+    #[test]
+    fn test_eui48_operators() {
+        let mut eui48 = Eui48::from(0x4D7E_5497_2EEF);
+
+        assert_eq!(eui48 + 0x11, Eui48::from(0x4D7E_5497_2F00));
+        assert_eq!(eui48 - 0xEF, Eui48::from(0x4D7E_5497_2E00));
+
+        eui48 += 1;
+        eui48 -= 2;
+        assert_eq!(eui48, Eui48::from(0x4D7E_5497_2EEE));
+    }
+
+    #[test]
+    #[should_panic(expected = "eui addition overflowed")]
+    fn test_eui48_add_overflow() {
+        let _ = Eui48::from(0xFFFF_FFFF_FFFF) + 1;
+    }
+
+    #[test]
+    fn test_eui48_wrapping_saturating() {
+        let last = Eui48::from(0xFFFF_FFFF_FFFF);
+        let first = Eui48::from(0);
+
+        assert_eq!(last.wrapping_add(2), Eui48::from(1));
+        assert_eq!(first.wrapping_sub(1), last);
+        assert_eq!(last.saturating_add(2), last);
+        assert_eq!(first.saturating_sub(1), first);
+        assert_eq!(first.saturating_add(u64::MAX), last);
+    }
+
+    #[test]
+    fn test_eui64_wrapping_saturating() {
+        let last = Eui64::from(u64::MAX);
+
+        assert_eq!(last.wrapping_add(1), Eui64::from(0));
+        assert_eq!(Eui64::from(0).wrapping_sub(1), last);
+        assert_eq!(last.saturating_add(1), last);
+        assert_eq!(Eui64::from(1) + 1, Eui64::from(2));
+    }
+
+    #[test]
+    fn test_eui48_bitwise() {
+        let eui48 = Eui48::from(85204980412143);
+        let mask = Eui48::from(0xFFFF_FF00_0000);
+
+        assert_eq!(eui48 & mask, Eui48::from(0x4D7E_5400_0000));
+        assert_eq!(eui48 | mask, Eui48::from(0xFFFF_FF97_2EEF));
+        assert_eq!(eui48 ^ eui48, Eui48::from(0));
+        assert_eq!(!mask, Eui48::from(0x0000_00FF_FFFF));
+        assert_eq!(
+            eui48 & Eui48Mask::from_prefix(eui48, 24).unwrap(),
+            eui48 & mask
+        );
+
+        let mut local = eui48;
+        local |= Eui48::from(0x0200_0000_0000);
+        local &= !Eui48::from(0x0100_0000_0000);
+        local ^= Eui48::from(1);
+        assert_eq!(local, Eui48::from(0x4E7E_5497_2EEE));
+    }
+
+    #[test]
+    fn test_eui64_bitwise() {
+        let eui64 = Eui64::from(5583992946972634863);
+
+        assert_eq!(eui64 & Eui64::from(0xFF), Eui64::from(0xEF));
+        assert_eq!(!Eui64::from(0), Eui64::from(u64::MAX));
+    }
+
+    #[test]
+    fn test_distance() {
+        let eui48 = Eui48::from(85204980412143);
+
+        assert_eq!(eui48.distance(&eui48), 0);
+        assert_eq!(eui48.distance(&(eui48 + 0x100)), 0x100);
+        assert_eq!((eui48 + 0x100).distance(&eui48), 0x100);
+        assert_eq!(Eui64::from(0).distance(&Eui64::from(u64::MAX)), u64::MAX);
+    }
+}
@@ -0,0 +1,134 @@
+use crate::{BufferTooSmall, Eui48, Eui64};
+
+/// Copies octets into the beginning of buffer.
+fn encode(octets: &[u8], buf: &mut [u8]) -> Result<usize, BufferTooSmall> {
+    let required = octets.len();
+
+    match buf.get_mut(..required) {
+        Some(dest) => {
+            dest.copy_from_slice(octets);
+            Ok(required)
+        }
+        None => Err(BufferTooSmall { required }),
+    }
+}
+
+/// Reads octets from the beginning of buffer.
+fn decode<const N: usize>(buf: &[u8]) -> Result<[u8; N], BufferTooSmall> {
+    let mut octets = [0; N];
+
+    match buf.get(..N) {
+        Some(src) => {
+            octets.copy_from_slice(src);
+            Ok(octets)
+        }
+        None => Err(BufferTooSmall { required: N }),
+    }
+}
+
+impl Eui48 {
+    /// Length of binary form written by `encode`.
+    pub const ENCODED_LEN: usize = 6;
+
+    /// Writes eui as 6 octets in transmission (big-endian) order, returning number of bytes
+    /// written.
+    ///
+    /// This layout is stable and does not depend on serde or any other format.
+    #[inline]
+    pub fn encode(&self, buf: &mut [u8]) -> Result<usize, BufferTooSmall> {
+        encode(&self.0, buf)
+    }
+
+    /// Reads eui from the first 6 octets of buffer written by `encode`.
+    #[inline]
+    pub fn decode(buf: &[u8]) -> Result<Self, BufferTooSmall> {
+        decode(buf).map(Eui48::new)
+    }
+}
+
+impl Eui64 {
+    /// Length of binary form written by `encode`.
+    pub const ENCODED_LEN: usize = 8;
+
+    /// Writes eui as 8 octets in transmission (big-endian) order, returning number of bytes
+    /// written.
+    ///
+    /// This layout is stable and does not depend on serde or any other format.
+    #[inline]
+    pub fn encode(&self, buf: &mut [u8]) -> Result<usize, BufferTooSmall> {
+        encode(&self.0, buf)
+    }
+
+    /// Reads eui from the first 8 octets of buffer written by `encode`.
+    #[inline]
+    pub fn decode(buf: &[u8]) -> Result<Self, BufferTooSmall> {
+        decode(buf).map(Eui64::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BufferTooSmall, Eui48, Eui64};
+
+    #[test]
+    fn test_eui48_encode() {
+        let eui48 = Eui48::from(85204980412143);
+        let mut buf = [0; 8];
+
+        assert_eq!(eui48.encode(&mut buf), Ok(6));
+        assert_eq!(buf, [0x4d, 0x7e, 0x54, 0x97, 0x2e, 0xef, 0, 0]);
+        assert_eq!(Eui48::decode(&buf), Ok(eui48));
+        assert_eq!(
+            eui48.encode(&mut buf[..5]),
+            Err(BufferTooSmall { required: 6 })
+        );
+        assert_eq!(
+            Eui48::decode(&buf[..5]),
+            Err(BufferTooSmall { required: 6 })
+        );
+    }
+
+    #[test]
+    fn test_eui64_encode() {
+        let eui64 = Eui64::from(5583992946972634863);
+        let mut buf = [0; Eui64::ENCODED_LEN];
+
+        assert_eq!(eui64.encode(&mut buf), Ok(8));
+        assert_eq!(buf, [0x4d, 0x7e, 0x54, 0, 0, 0x97, 0x2e, 0xef]);
+        assert_eq!(Eui64::decode(&buf), Ok(eui64));
+        assert_eq!(Eui64::decode(&[]), Err(BufferTooSmall { required: 8 }));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_encode_matches_postcard() {
+        let eui48 = Eui48::from(85204980412143);
+        let eui64 = Eui64::from(5583992946972634863);
+        let mut encoded = [0; 8];
+        let mut buf = [0; 16];
+
+        eui48.encode(&mut encoded).unwrap();
+        assert_eq!(postcard::to_slice(&eui48, &mut buf).unwrap(), &encoded[..6]);
+        assert_eq!(postcard::from_bytes::<Eui48>(&encoded[..6]), Ok(eui48));
+
+        eui64.encode(&mut encoded).unwrap();
+        assert_eq!(postcard::to_slice(&eui64, &mut buf).unwrap(), &encoded[..]);
+        assert_eq!(postcard::from_bytes::<Eui64>(&encoded), Ok(eui64));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_encode_matches_bincode() {
+        let eui48 = Eui48::from(85204980412143);
+        let eui64 = Eui64::from(5583992946972634863);
+        let mut encoded = [0; 8];
+
+        eui48.encode(&mut encoded).unwrap();
+        assert_eq!(bincode::serialize(&eui48).unwrap(), &encoded[..6]);
+        assert_eq!(bincode::deserialize::<Eui48>(&encoded[..6]).unwrap(), eui48);
+
+        eui64.encode(&mut encoded).unwrap();
+        assert_eq!(bincode::serialize(&eui64).unwrap(), &encoded[..]);
+        assert_eq!(bincode::deserialize::<Eui64>(&encoded).unwrap(), eui64);
+    }
+}
@@ -0,0 +1,244 @@
+use crate::{Eui48, Eui48Range, EuiFormat};
+use core::fmt::{self, Display, Formatter};
+
+/// Block of `len` consecutive euis starting at `base`, e.g. address space bought from IEEE
+/// or assigned to a production batch.
+#[derive(Eq, PartialEq, Copy, Clone, Hash, Debug)]
+pub struct Eui48Block {
+    base: Eui48,
+    len: u64,
+}
+
+/// Error returned when consecutive euis can't be allocated from base address.
+#[derive(Debug, PartialEq, Eq)]
+pub enum BlockError {
+    /// Block would extend past the last eui.
+    Overflow,
+    /// Block would cross into another OUI, `last` is the last eui it would contain.
+    CrossesOui { last: Eui48 },
+}
+
+impl Display for BlockError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            BlockError::Overflow => f.write_str("block extends past the last eui"),
+            BlockError::CrossesOui { last } => {
+                write!(f, "block crosses into another OUI, ending at {}", last)
+            }
+        }
+    }
+}
+
+impl core::error::Error for BlockError {}
+
+impl Eui48 {
+    /// Allocates `count` consecutive euis starting at `base`, checking that the block neither
+    /// wraps around nor leaves OUI of `base`.
+    pub fn try_allocate_block(base: Eui48, count: u64) -> Result<Eui48Block, BlockError> {
+        let block = Eui48Block::new(base, count).ok_or(BlockError::Overflow)?;
+
+        match block.last() {
+            Some(last) if last.oui() != base.oui() => Err(BlockError::CrossesOui { last }),
+            _ => Ok(block),
+        }
+    }
+}
+
+impl Eui48Block {
+    /// Creates block, `None` if it extends past the last eui.
+    pub fn new(base: Eui48, len: u64) -> Option<Self> {
+        if len > 0 {
+            base.checked_add(len - 1)?;
+        }
+
+        Some(Eui48Block { base, len })
+    }
+
+    /// Returns first eui of block.
+    #[inline]
+    pub fn base(&self) -> Eui48 {
+        self.base
+    }
+
+    /// Returns number of euis in block.
+    #[inline]
+    pub fn len(&self) -> u64 {
+        self.len
+    }
+
+    /// Returns `true` if block has no euis.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns last eui of block, `None` for empty block.
+    pub fn last(&self) -> Option<Eui48> {
+        self.len.checked_sub(1).map(|offset| self.base + offset)
+    }
+
+    /// Returns `true` if eui belongs to block.
+    pub fn contains(&self, eui: &Eui48) -> bool {
+        match u64::from(*eui).checked_sub(u64::from(self.base)) {
+            Some(offset) => offset < self.len,
+            None => false,
+        }
+    }
+
+    /// Returns `true` if blocks share at least one eui.
+    pub fn overlaps(&self, other: &Eui48Block) -> bool {
+        match (self.last(), other.last()) {
+            (Some(last), Some(other_last)) => self.base <= other_last && other.base <= last,
+            _ => false,
+        }
+    }
+
+    /// Splits block into first `mid` euis and the rest, `None` if `mid` exceeds length.
+    pub fn split_at(&self, mid: u64) -> Option<(Eui48Block, Eui48Block)> {
+        if mid > self.len {
+            return None;
+        }
+
+        Some((
+            Eui48Block {
+                base: self.base,
+                len: mid,
+            },
+            Eui48Block {
+                base: self.base.wrapping_add(mid),
+                len: self.len - mid,
+            },
+        ))
+    }
+
+    /// Returns iterator over euis of block.
+    pub fn iter(&self) -> Eui48Range {
+        match self.last() {
+            Some(last) => Eui48Range::new(self.base, last),
+            None => Eui48Range::default(),
+        }
+    }
+}
+
+impl IntoIterator for Eui48Block {
+    type Item = Eui48;
+    type IntoIter = Eui48Range;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Prints base and length separated by plus, e.g. `4D-7E-54-97-2E-EF+16`, alternate flag
+/// (`{:#}`) prints base in lowercase colon separated form.
+impl Display for Eui48Block {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let format = if f.alternate() {
+            EuiFormat::COLON_LOWER
+        } else {
+            EuiFormat::CANONICAL
+        };
+
+        write!(f, "{}+{}", self.base.format_with(format), self.len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use crate::{BlockError, Eui48, Eui48Block};
+    use std::format;
+
+    #[test]
+    fn test_block_error_display() {
+        assert_eq!(
+            format!("{}", BlockError::Overflow),
+            "block extends past the last eui"
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                BlockError::CrossesOui {
+                    last: Eui48::from(0x4D7E_5500_0001)
+                }
+            ),
+            "block crosses into another OUI, ending at 4D-7E-55-00-00-01"
+        );
+    }
+
+    #[test]
+    fn test_block_new() {
+        let last = Eui48::from(0xFFFF_FFFF_FFFF);
+
+        assert!(Eui48Block::new(last, 1).is_some());
+        assert!(Eui48Block::new(last, 2).is_none());
+        assert!(Eui48Block::new(last, 0).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_block_contains() {
+        let block = Eui48Block::new(Eui48::from(0x4D7E_5400_0000), 0x100).unwrap();
+
+        assert_eq!(block.last(), Some(Eui48::from(0x4D7E_5400_00FF)));
+        assert!(block.contains(&Eui48::from(0x4D7E_5400_0000)));
+        assert!(block.contains(&Eui48::from(0x4D7E_5400_00FF)));
+        assert!(!block.contains(&Eui48::from(0x4D7E_5400_0100)));
+        assert!(!block.contains(&Eui48::from(0x4D7E_53FF_FFFF)));
+    }
+
+    #[test]
+    fn test_block_overlaps() {
+        let block = Eui48Block::new(Eui48::from(0x100), 0x100).unwrap();
+
+        assert!(block.overlaps(&Eui48Block::new(Eui48::from(0x1FF), 1).unwrap()));
+        assert!(block.overlaps(&Eui48Block::new(Eui48::from(0), 0x101).unwrap()));
+        assert!(!block.overlaps(&Eui48Block::new(Eui48::from(0x200), 1).unwrap()));
+        assert!(!block.overlaps(&Eui48Block::new(Eui48::from(0x180), 0).unwrap()));
+    }
+
+    #[test]
+    fn test_block_split_and_iter() {
+        let block = Eui48Block::new(Eui48::from(0x100), 4).unwrap();
+        let (head, tail) = block.split_at(1).unwrap();
+
+        assert_eq!(
+            head.iter().collect::<heapless::Vec<_, 4>>(),
+            [Eui48::from(0x100)]
+        );
+        assert_eq!(tail.base(), Eui48::from(0x101));
+        assert_eq!(tail.len(), 3);
+        assert_eq!(tail.into_iter().len(), 3);
+        assert_eq!(block.split_at(5), None);
+        assert_eq!(block.split_at(4).unwrap().1.iter().next(), None);
+    }
+
+    #[test]
+    fn test_try_allocate_block() {
+        let base = Eui48::from(0x4D7E_54FF_FF00);
+
+        assert_eq!(
+            Eui48::try_allocate_block(base, 0x100),
+            Ok(Eui48Block::new(base, 0x100).unwrap())
+        );
+        assert_eq!(
+            Eui48::try_allocate_block(base, 0x101),
+            Err(BlockError::CrossesOui {
+                last: Eui48::from(0x4D7E_5500_0000)
+            })
+        );
+        assert_eq!(
+            Eui48::try_allocate_block(Eui48::from(0xFFFF_FFFF_FFFF), 2),
+            Err(BlockError::Overflow)
+        );
+        assert!(Eui48::try_allocate_block(base, 0).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_block_display() {
+        let block = Eui48Block::new(Eui48::from(85204980412143), 16).unwrap();
+
+        assert_eq!(format!("{}", block), "4D-7E-54-97-2E-EF+16");
+        assert_eq!(format!("{:#}", block), "4d:7e:54:97:2e:ef+16");
+    }
+}
@@ -0,0 +1,174 @@
+use crate::{Eui, Eui48, Eui64, LOCAL_BIT};
+use core::cmp::Ordering;
+
+/// Middle octets of eui-64 encapsulating EUI-48.
+const EUI48_ENCAPSULATION: [u8; 2] = [0xFF, 0xFE];
+
+/// Middle octets of eui-64 encapsulating MAC-48.
+const MAC48_ENCAPSULATION: [u8; 2] = [0xFF, 0xFF];
+
+fn encapsulates(eui64: &Eui64, eui48: &Eui48, middle: &[[u8; 2]]) -> bool {
+    eui64.0[..3] == eui48.0[..3]
+        && eui64.0[5..] == eui48.0[3..]
+        && middle.contains(&[eui64.0[3], eui64.0[4]])
+}
+
+impl<const N: usize> Eui<N> {
+    /// Returns `true` if euis are equal apart from U/L bit.
+    #[inline]
+    pub fn eq_ignore_local_bit(&self, other: &Self) -> bool {
+        (self.0[0] | LOCAL_BIT) == (other.0[0] | LOCAL_BIT) && self.0[1..] == other.0[1..]
+    }
+}
+
+impl Eui64 {
+    /// Returns `true` if eui is IEEE encapsulation of eui-48, with `FF-FE` (EUI-48) or
+    /// `FF-FF` (MAC-48) inserted after oui.
+    #[inline]
+    pub fn encapsulates(&self, eui48: &Eui48) -> bool {
+        encapsulates(self, eui48, &[EUI48_ENCAPSULATION, MAC48_ENCAPSULATION])
+    }
+}
+
+/// Equal when eui-64 is eui-48 with `FF-FE` inserted after oui.
+impl PartialEq<Eui64> for Eui48 {
+    fn eq(&self, other: &Eui64) -> bool {
+        encapsulates(other, self, &[EUI48_ENCAPSULATION])
+    }
+}
+
+/// Equal when eui-64 is eui-48 with `FF-FE` inserted after oui.
+impl PartialEq<Eui48> for Eui64 {
+    fn eq(&self, other: &Eui48) -> bool {
+        other == self
+    }
+}
+
+impl<const N: usize> PartialEq<[u8; N]> for Eui<N> {
+    #[inline]
+    fn eq(&self, other: &[u8; N]) -> bool {
+        self.0 == *other
+    }
+}
+
+impl<const N: usize> PartialEq<Eui<N>> for [u8; N] {
+    #[inline]
+    fn eq(&self, other: &Eui<N>) -> bool {
+        *self == other.0
+    }
+}
+
+impl<const N: usize> PartialOrd<[u8; N]> for Eui<N> {
+    #[inline]
+    fn partial_cmp(&self, other: &[u8; N]) -> Option<Ordering> {
+        self.0.partial_cmp(other)
+    }
+}
+
+impl<const N: usize> PartialOrd<Eui<N>> for [u8; N] {
+    #[inline]
+    fn partial_cmp(&self, other: &Eui<N>) -> Option<Ordering> {
+        self.partial_cmp(&other.0)
+    }
+}
+
+macro_rules! integer_cmp {
+    ($name:ident) => {
+        impl PartialEq<u64> for $name {
+            #[inline]
+            fn eq(&self, other: &u64) -> bool {
+                u64::from(*self) == *other
+            }
+        }
+
+        impl PartialEq<$name> for u64 {
+            #[inline]
+            fn eq(&self, other: &$name) -> bool {
+                *self == u64::from(*other)
+            }
+        }
+
+        impl PartialOrd<u64> for $name {
+            #[inline]
+            fn partial_cmp(&self, other: &u64) -> Option<Ordering> {
+                u64::from(*self).partial_cmp(other)
+            }
+        }
+
+        impl PartialOrd<$name> for u64 {
+            #[inline]
+            fn partial_cmp(&self, other: &$name) -> Option<Ordering> {
+                self.partial_cmp(&u64::from(*other))
+            }
+        }
+    };
+}
+
+integer_cmp!(Eui48);
+integer_cmp!(Eui64);
+
+#[cfg(test)]
+mod tests {
+    use crate::{Eui48, Eui64};
+
+    #[test]
+    fn test_cross_width_eq() {
+        let eui48 = Eui48::from(85204980412143);
+
+        assert_eq!(Eui64::from(0x4D7E_54FF_FE97_2EEF), eui48);
+        assert_eq!(eui48, Eui64::from(0x4D7E_54FF_FE97_2EEF));
+        assert_ne!(eui48, Eui64::from(0x4D7E_54FF_FF97_2EEF));
+        assert_ne!(eui48, Eui64::from(0x4D7E_5400_0097_2EEF));
+        assert_ne!(Eui64::from(0x4D7E_55FF_FE97_2EEF), eui48);
+    }
+
+    #[test]
+    fn test_encapsulates() {
+        let eui48 = Eui48::from(85204980412143);
+
+        assert!(Eui64::from(0x4D7E_54FF_FE97_2EEF).encapsulates(&eui48));
+        assert!(Eui64::from(0x4D7E_54FF_FF97_2EEF).encapsulates(&eui48));
+        assert!(!Eui64::from(0x4D7E_5400_0097_2EEF).encapsulates(&eui48));
+        assert!(!Eui64::from(0x4D7E_54FF_FE97_2EEE).encapsulates(&eui48));
+    }
+
+    #[test]
+    fn test_eui48_raw_cmp() {
+        let eui48 = Eui48::from(85204980412143);
+
+        assert_eq!(eui48, [0x4D, 0x7E, 0x54, 0x97, 0x2E, 0xEF]);
+        assert_eq!([0x4D, 0x7E, 0x54, 0x97, 0x2E, 0xEF], eui48);
+        assert_eq!(eui48, 85204980412143);
+        assert_eq!(85204980412143, eui48);
+
+        assert!(eui48 < [0x4D, 0x7E, 0x54, 0x97, 0x2E, 0xF0]);
+        assert!(eui48 > 0x4D7E_5497_2EEE);
+        assert!(eui48 < 0x0001_0000_0000_0000);
+        assert!(0x0001_0000_0000_0000 > eui48);
+    }
+
+    #[test]
+    fn test_eui64_raw_cmp() {
+        let eui64 = Eui64::from(5583992946972634863);
+
+        assert_eq!(eui64, [0x4D, 0x7E, 0x54, 0x00, 0x00, 0x97, 0x2E, 0xEF]);
+        assert_eq!(eui64, 5583992946972634863);
+        assert!(eui64 >= [0x4D, 0x7E, 0x54, 0x00, 0x00, 0x97, 0x2E, 0xEF]);
+        assert!(u64::MAX > eui64);
+    }
+
+    #[test]
+    fn test_eq_ignore_local_bit() {
+        let eui48 = Eui48::from(0x4C7E_5497_2EEF);
+
+        assert!(eui48.eq_ignore_local_bit(&Eui48::from(0x4E7E_5497_2EEF)));
+        assert!(eui48.eq_ignore_local_bit(&eui48));
+        assert!(!eui48.eq_ignore_local_bit(&Eui48::from(0x4D7E_5497_2EEF)));
+        assert!(!eui48.eq_ignore_local_bit(&Eui48::from(0x4E7E_5497_2EEE)));
+
+        let eui64 = Eui64::from(0x4C7E_5400_0097_2EEF);
+
+        assert!(eui64.eq_ignore_local_bit(&Eui64::from(0x4E7E_5400_0097_2EEF)));
+        assert!(!eui64.eq_ignore_local_bit(&Eui64::from(0x4E7E_5400_0097_2EEE)));
+    }
+}
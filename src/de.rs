@@ -1,11 +1,131 @@
-use crate::{string_to_eui, Eui48, Eui64, StringToEuiError};
+use crate::{
+    check_string_length, string_to_eui, BareHex, Dotted, Eui48, Eui48Block, Eui48Mask,
+    Eui48Pattern, Eui64, LowerColon, Oui, EUI48_STRING_LENGTHS, EUI64_STRING_LENGTHS,
+};
+use core::convert::TryFrom;
 use core::fmt;
 use serde::de::Visitor;
-use serde::de::{Error, Unexpected};
+use serde::de::{Error, Expected, SeqAccess, Unexpected};
 use serde::{Deserialize, Deserializer};
 
-struct Eui48Visitor;
-struct Eui64Visitor;
+/// Visitor producing `Eui48` from a string, 6 octets or 48 bit integer.
+#[derive(Eq, PartialEq, Copy, Clone, Debug, Default)]
+pub struct Eui48Visitor;
+
+/// Visitor producing `Eui64` from a string, 8 octets or 64 bit integer.
+#[derive(Eq, PartialEq, Copy, Clone, Debug, Default)]
+pub struct Eui64Visitor;
+
+struct OuiVisitor;
+
+fn octets_from_bytes<E: Error, const N: usize>(
+    v: &[u8],
+    expected: &dyn Expected,
+) -> Result<[u8; N], E> {
+    <[u8; N]>::try_from(v).map_err(|_| Error::invalid_length(v.len(), expected))
+}
+
+fn octets_from_seq<'de, A: SeqAccess<'de>, const N: usize>(
+    mut seq: A,
+    expected: &dyn Expected,
+) -> Result<[u8; N], A::Error> {
+    let mut octets = [0; N];
+
+    for (i, octet) in octets.iter_mut().enumerate() {
+        *octet = seq
+            .next_element()?
+            .ok_or_else(|| Error::invalid_length(i, expected))?;
+    }
+
+    if seq.next_element::<u8>()?.is_some() {
+        return Err(Error::invalid_length(N + 1, expected));
+    }
+
+    Ok(octets)
+}
+
+fn octets_from_u64<E: Error, const N: usize>(
+    v: u64,
+    expected: &dyn Expected,
+) -> Result<[u8; N], E> {
+    let bytes = v.to_be_bytes();
+    let (high, low) = bytes.split_at(8 - N);
+
+    if high.iter().any(|&b| b != 0) {
+        return Err(Error::invalid_value(Unexpected::Unsigned(v), expected));
+    }
+
+    Ok(<[u8; N]>::try_from(low).unwrap_or([0; N]))
+}
+
+/// Deserializes directly into octets of an existing eui.
+struct InPlaceVisitor<'a, const N: usize> {
+    place: &'a mut [u8; N],
+    lengths: &'static [usize],
+}
+
+impl<'de, const N: usize> Visitor<'de> for InPlaceVisitor<'_, N> {
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "eui string, {} octets or {} bit integer",
+            N,
+            N * 8
+        )
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        check_string_length(v.len(), self.lengths)
+            .and_then(|()| string_to_eui(v, self.place))
+            .map_err(Error::custom)
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        *self.place = octets_from_bytes(v, &self)?;
+        Ok(())
+    }
+
+    fn visit_borrowed_bytes<E>(self, v: &'de [u8]) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        self.visit_bytes(v)
+    }
+
+    fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        *self.place = octets_from_seq(seq, &self)?;
+        Ok(())
+    }
+
+    fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        *self.place = octets_from_u64(v, &self)?;
+        Ok(())
+    }
+
+    fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        match u64::try_from(v) {
+            Ok(v) => self.visit_u64(v),
+            Err(_) => Err(Error::invalid_value(Unexpected::Signed(v), &self)),
+        }
+    }
+}
 
 impl<'de> Visitor<'de> for Eui48Visitor {
     type Value = Eui48;
@@ -13,8 +133,10 @@ impl<'de> Visitor<'de> for Eui48Visitor {
     fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
         write!(
             formatter,
-            "12 byte string with only hexadecimal characters or \
-             17 byte string with hexadecimal characters and separator after every second character"
+            "12 byte string with only hexadecimal characters, \
+             14 byte string in dotted form or \
+             17 byte string with hexadecimal characters and separator after every second character, \
+             6 octets or 48 bit integer"
         )
     }
 
@@ -22,28 +144,44 @@ impl<'de> Visitor<'de> for Eui48Visitor {
     where
         E: Error,
     {
-        if v.len() != 12 && v.len() != 17 {
-            return Err(Error::invalid_length(v.len(), &self));
-        }
+        Eui48::try_from(v).map_err(Error::custom)
+    }
 
-        let mut result = [0; 6];
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        octets_from_bytes(v, &self).map(Eui48::new)
+    }
 
-        match string_to_eui(v, &mut result[..]) {
-            Err(StringToEuiError::InvalidLength { length }) => {
-                return Err(Error::invalid_length(length, &self));
-            }
-            Err(StringToEuiError::InvalidChar { char }) => {
-                return Err(Error::invalid_value(Unexpected::Char(char), &self));
-            }
-            Err(StringToEuiError::InvalidSeparatorPlace) => {
-                return Err(Error::custom(
-                    "Separator must be placed after every second character",
-                ))
-            }
-            Err(StringToEuiError::OnlyOneSeparatorTypeExpected) => {
-                return Err(Error::custom("Only one type of separator should be used"));
-            }
-            Ok(()) => return Ok(Eui48(result)),
+    fn visit_borrowed_bytes<E>(self, v: &'de [u8]) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        self.visit_bytes(v)
+    }
+
+    fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        octets_from_seq(seq, &self).map(Eui48::new)
+    }
+
+    fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        octets_from_u64(v, &self).map(Eui48::new)
+    }
+
+    fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        match u64::try_from(v) {
+            Ok(v) => self.visit_u64(v),
+            Err(_) => Err(Error::invalid_value(Unexpected::Signed(v), &self)),
         }
     }
 }
@@ -54,8 +192,10 @@ impl<'de> Visitor<'de> for Eui64Visitor {
     fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
         write!(
             formatter,
-            "16 byte string with only hexadecimal characters or \
-             23 byte string with hexadecimal characters and separator after every second character"
+            "16 byte string with only hexadecimal characters, \
+             19 byte string in dotted form or \
+             23 byte string with hexadecimal characters and separator after every second character, \
+             8 octets or 64 bit integer"
         )
     }
 
@@ -63,28 +203,44 @@ impl<'de> Visitor<'de> for Eui64Visitor {
     where
         E: Error,
     {
-        if v.len() != 16 && v.len() != 23 {
-            return Err(Error::invalid_length(v.len(), &self));
-        }
+        Eui64::try_from(v).map_err(Error::custom)
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        octets_from_bytes(v, &self).map(Eui64::new)
+    }
 
-        let mut result = [0; 8];
+    fn visit_borrowed_bytes<E>(self, v: &'de [u8]) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        self.visit_bytes(v)
+    }
 
-        match string_to_eui(v, &mut result[..]) {
-            Err(StringToEuiError::InvalidLength { length }) => {
-                return Err(Error::invalid_length(length, &self));
-            }
-            Err(StringToEuiError::InvalidChar { char }) => {
-                return Err(Error::invalid_value(Unexpected::Char(char), &self));
-            }
-            Err(StringToEuiError::InvalidSeparatorPlace) => {
-                return Err(Error::custom(
-                    "Separator must be placed after every second character",
-                ))
-            }
-            Err(StringToEuiError::OnlyOneSeparatorTypeExpected) => {
-                return Err(Error::custom("Only one type of separator should be used"));
-            }
-            Ok(()) => return Ok(Eui64(result)),
+    fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        octets_from_seq(seq, &self).map(Eui64::new)
+    }
+
+    fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        Ok(Eui64::from(v))
+    }
+
+    fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        match u64::try_from(v) {
+            Ok(v) => self.visit_u64(v),
+            Err(_) => Err(Error::invalid_value(Unexpected::Signed(v), &self)),
         }
     }
 }
@@ -94,7 +250,27 @@ impl<'de> Deserialize<'de> for Eui48 {
     where
         D: Deserializer<'de>,
     {
-        deserializer.deserialize_str(Eui48Visitor)
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(Eui48Visitor)
+        } else {
+            deserializer.deserialize_tuple(6, Eui48Visitor)
+        }
+    }
+
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let visitor = InPlaceVisitor {
+            place: &mut place.0,
+            lengths: &EUI48_STRING_LENGTHS,
+        };
+
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(visitor)
+        } else {
+            deserializer.deserialize_tuple(6, visitor)
+        }
     }
 }
 
@@ -103,19 +279,275 @@ impl<'de> Deserialize<'de> for Eui64 {
     where
         D: Deserializer<'de>,
     {
-        deserializer.deserialize_str(Eui64Visitor)
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(Eui64Visitor)
+        } else {
+            deserializer.deserialize_tuple(8, Eui64Visitor)
+        }
+    }
+
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let visitor = InPlaceVisitor {
+            place: &mut place.0,
+            lengths: &EUI64_STRING_LENGTHS,
+        };
+
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(visitor)
+        } else {
+            deserializer.deserialize_tuple(8, visitor)
+        }
+    }
+}
+
+impl<'de> Visitor<'de> for OuiVisitor {
+    type Value = Oui;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "6 byte string with only hexadecimal characters, \
+             8 byte string with hexadecimal characters and separator after every second character \
+             or 3 octets"
+        )
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        Oui::try_from(v).map_err(Error::custom)
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        octets_from_bytes(v, &self).map(Oui)
+    }
+
+    fn visit_borrowed_bytes<E>(self, v: &'de [u8]) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        self.visit_bytes(v)
+    }
+
+    fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        octets_from_seq(seq, &self).map(Oui)
+    }
+}
+
+impl<'de> Deserialize<'de> for Oui {
+    fn deserialize<D>(deserializer: D) -> Result<Self, <D>::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(OuiVisitor)
+        } else {
+            deserializer.deserialize_tuple(3, OuiVisitor)
+        }
+    }
+}
+
+struct BlockVisitor;
+
+impl<'de> Visitor<'de> for BlockVisitor {
+    type Value = Eui48Block;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "string with base eui and block length separated by plus \
+             or tuple of base eui and block length"
+        )
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        v.find('+')
+            .and_then(|plus| {
+                let base = Eui48::try_from(&v[..plus]).ok()?;
+                let len = v[plus + 1..].parse().ok()?;
+
+                Eui48Block::new(base, len)
+            })
+            .ok_or_else(|| Error::invalid_value(Unexpected::Str(v), &self))
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let base: Eui48 = seq
+            .next_element()?
+            .ok_or_else(|| Error::invalid_length(0, &self))?;
+        let len: u64 = seq
+            .next_element()?
+            .ok_or_else(|| Error::invalid_length(1, &self))?;
+
+        Eui48Block::new(base, len).ok_or_else(|| {
+            Error::invalid_value(Unexpected::Unsigned(len), &"block within eui space")
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for Eui48Block {
+    fn deserialize<D>(deserializer: D) -> Result<Self, <D>::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(BlockVisitor)
+        } else {
+            deserializer.deserialize_tuple(2, BlockVisitor)
+        }
+    }
+}
+
+struct MaskVisitor;
+
+impl<'de> Visitor<'de> for MaskVisitor {
+    type Value = Eui48Mask;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "string with eui pattern and eui mask or prefix length separated by slash \
+             or tuple of pattern and mask"
+        )
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        Eui48Mask::try_from(v).map_err(|_| Error::invalid_value(Unexpected::Str(v), &self))
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let pattern = seq
+            .next_element()?
+            .ok_or_else(|| Error::invalid_length(0, &self))?;
+        let mask = seq
+            .next_element()?
+            .ok_or_else(|| Error::invalid_length(1, &self))?;
+
+        Ok(Eui48Mask::new(pattern, mask))
+    }
+}
+
+impl<'de> Deserialize<'de> for Eui48Mask {
+    fn deserialize<D>(deserializer: D) -> Result<Self, <D>::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(MaskVisitor)
+        } else {
+            deserializer.deserialize_tuple(2, MaskVisitor)
+        }
+    }
+}
+
+struct PatternVisitor;
+
+impl<'de> Visitor<'de> for PatternVisitor {
+    type Value = Eui48Pattern;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "glob-style eui pattern like 4D:7E:54:*:*:* or tuple of pattern and mask \
+             covering whole hexadecimal digits"
+        )
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        Eui48Pattern::parse(v).map_err(Error::custom)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let pattern = seq
+            .next_element()?
+            .ok_or_else(|| Error::invalid_length(0, &self))?;
+        let mask = seq
+            .next_element()?
+            .ok_or_else(|| Error::invalid_length(1, &self))?;
+
+        Eui48Pattern::from_mask(Eui48Mask::new(pattern, mask))
+            .ok_or_else(|| Error::invalid_value(Unexpected::Other("partial digit mask"), &self))
+    }
+}
+
+impl<'de> Deserialize<'de> for Eui48Pattern {
+    fn deserialize<D>(deserializer: D) -> Result<Self, <D>::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(PatternVisitor)
+        } else {
+            deserializer.deserialize_tuple(2, PatternVisitor)
+        }
     }
 }
 
+macro_rules! deserialize_wrapper {
+    ($name: ident) => {
+        impl<'de> Deserialize<'de> for $name<Eui48> {
+            fn deserialize<D>(deserializer: D) -> Result<Self, <D>::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                deserializer.deserialize_str(Eui48Visitor).map($name)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name<Eui64> {
+            fn deserialize<D>(deserializer: D) -> Result<Self, <D>::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                deserializer.deserialize_str(Eui64Visitor).map($name)
+            }
+        }
+    };
+}
+
+deserialize_wrapper!(LowerColon);
+deserialize_wrapper!(BareHex);
+deserialize_wrapper!(Dotted);
+
 #[cfg(test)]
 mod tests {
-    use crate::{Eui48, Eui64};
-    use serde_test::{assert_de_tokens, assert_de_tokens_error, Token};
+    use crate::{BareHex, Eui48, Eui48Block, Eui48Mask, Eui48Pattern, Eui64, LowerColon, Oui};
+    use serde_test::{
+        assert_de_tokens, assert_de_tokens_error, Compact, Configure, Readable, Token,
+    };
 
     #[test]
     fn test_eui48_deserialize_lowercase() {
         assert_de_tokens(
-            &Eui48::from(85204980412143),
+            &Eui48::from(85204980412143).readable(),
             &[Token::String("4d7e54972eef")],
         );
     }
@@ -123,7 +555,7 @@ mod tests {
     #[test]
     fn test_eui48_deserialize_uppercase() {
         assert_de_tokens(
-            &Eui48::from(85204980412143),
+            &Eui48::from(85204980412143).readable(),
             &[Token::String("4D7E54972EEF")],
         );
     }
@@ -131,7 +563,7 @@ mod tests {
     #[test]
     fn test_eui64_deserialize_lowercase() {
         assert_de_tokens(
-            &Eui64::from(5583992946972634863),
+            &Eui64::from(5583992946972634863).readable(),
             &[Token::String("4d7e540000972eef")],
         );
     }
@@ -139,87 +571,88 @@ mod tests {
     #[test]
     fn test_eui64_deserialize_uppercase() {
         assert_de_tokens(
-            &Eui64::from(5583992946972634863),
+            &Eui64::from(5583992946972634863).readable(),
             &[Token::String("4D7E540000972EEF")],
         );
     }
 
     #[test]
     fn test_eui48_deserialize_invalid_length() {
-        assert_de_tokens_error::<Eui48>(
+        assert_de_tokens_error::<Readable<Eui48>>(
             &[Token::Str("4d7e54972e")],
-            "invalid length 10, expected 12 byte string with only hexadecimal characters or \
-             17 byte string with hexadecimal characters and separator after every second character",
+            "invalid eui string length 10",
         );
 
-        assert_de_tokens_error::<Eui48>(
+        assert_de_tokens_error::<Readable<Eui48>>(
             &[Token::Str("4d7e54972eefef4d")],
-            "invalid length 16, expected 12 byte string with only hexadecimal characters or \
-             17 byte string with hexadecimal characters and separator after every second character",
+            "invalid eui string length 16",
         );
 
-        assert_de_tokens_error::<Eui48>(
+        assert_de_tokens_error::<Readable<Eui48>>(
             &[Token::Str("4d7e54972eefef4da")],
-            "invalid length 17, expected 12 byte string with only hexadecimal characters or \
-             17 byte string with hexadecimal characters and separator after every second character",
+            "unexpected characters after eui at position 12",
         );
     }
 
     #[test]
     fn test_eui64_deserialize_invalid_length() {
-        assert_de_tokens_error::<Eui64>(
+        assert_de_tokens_error::<Readable<Eui64>>(
             &[Token::Str("4d7e54972eaa")],
-            "invalid length 12, expected 16 byte string with only hexadecimal characters or \
-             23 byte string with hexadecimal characters and separator after every second character",
+            "invalid eui string length 12",
         );
 
-        assert_de_tokens_error::<Eui64>(
+        assert_de_tokens_error::<Readable<Eui64>>(
             &[Token::Str("4d7e54972eefef4ddd")],
-            "invalid length 18, expected 16 byte string with only hexadecimal characters or \
-             23 byte string with hexadecimal characters and separator after every second character",
+            "invalid eui string length 18",
         );
     }
 
     #[test]
     fn test_eui48_deserialize_invalid_character() {
-        assert_de_tokens_error::<Eui48>(
+        assert_de_tokens_error::<Readable<Eui48>>(
             &[Token::Str("ad7e54972esa")],
-            "invalid value: character `s`, expected 12 byte string with only hexadecimal characters or \
-            17 byte string with hexadecimal characters and separator after every second character",
+            "invalid character 's' at position 10",
         );
     }
 
     #[test]
     fn test_eui64_deserialize_invalid_character() {
-        assert_de_tokens_error::<Eui64>(
+        assert_de_tokens_error::<Readable<Eui64>>(
             &[Token::Str("ad7e54972ea721sa")],
-            "invalid value: character `s`, expected 16 byte string with only hexadecimal characters or \
-             23 byte string with hexadecimal characters and separator after every second character",
+            "invalid character 's' at position 14",
         );
     }
 
     #[test]
     fn test_eui48_deserialize_with_separator_lowercase() {
         assert_de_tokens(
-            &Eui48::from(85204980412143),
+            &Eui48::from(85204980412143).readable(),
             &[Token::String("4d:7e:54:97:2e:ef")],
         );
 
         assert_de_tokens(
-            &Eui48::from(85204980412143),
+            &Eui48::from(85204980412143).readable(),
             &[Token::String("4d-7e-54-97-2e-ef")],
         );
     }
 
+    #[test]
+    fn test_eui48_deserialize_dotted() {
+        assert_de_tokens(
+            &Eui48::from(85204980412143).readable(),
+            &[Token::String("4d7e.5497.2eef")],
+        );
+    }
+
     #[test]
     fn test_eui48_deserialize_with_separator_uppercase() {
         assert_de_tokens(
-            &Eui48::from(85204980412143),
+            &Eui48::from(85204980412143).readable(),
             &[Token::String("4D:7E:54:97:2E:EF")],
         );
 
         assert_de_tokens(
-            &Eui48::from(85204980412143),
+            &Eui48::from(85204980412143).readable(),
             &[Token::String("4D-7E-54-97-2E-EF")],
         );
     }
@@ -227,12 +660,12 @@ mod tests {
     #[test]
     fn test_eui64_deserialize_with_separator_lowercase() {
         assert_de_tokens(
-            &Eui64::from(5583992946972634863),
+            &Eui64::from(5583992946972634863).readable(),
             &[Token::String("4d:7e:54:00:00:97:2e:ef")],
         );
 
         assert_de_tokens(
-            &Eui64::from(5583992946972634863),
+            &Eui64::from(5583992946972634863).readable(),
             &[Token::String("4d-7e-54-00-00-97-2e-ef")],
         );
     }
@@ -240,65 +673,371 @@ mod tests {
     #[test]
     fn test_eui64_deserialize_with_separator_uppercase() {
         assert_de_tokens(
-            &Eui64::from(5583992946972634863),
+            &Eui64::from(5583992946972634863).readable(),
             &[Token::String("4D:7E:54:00:00:97:2E:EF")],
         );
 
         assert_de_tokens(
-            &Eui64::from(5583992946972634863),
+            &Eui64::from(5583992946972634863).readable(),
             &[Token::String("4D-7E-54-00-00-97-2E-EF")],
         );
     }
 
     #[test]
     fn test_eui48_deserialize_invalid_separator_position() {
-        assert_de_tokens_error::<Eui48>(
+        assert_de_tokens_error::<Readable<Eui48>>(
             &[Token::Str(":4d7e:54:97:2e:ef")],
-            "Separator must be placed after every second character",
+            "misplaced or missing separator at position 0",
         );
 
-        assert_de_tokens_error::<Eui48>(
+        assert_de_tokens_error::<Readable<Eui48>>(
             &[Token::Str("4d:7e:54:97:2eef:")],
-            "Separator must be placed after every second character",
+            "misplaced or missing separator at position 14",
         );
 
-        assert_de_tokens_error::<Eui48>(
+        assert_de_tokens_error::<Readable<Eui48>>(
             &[Token::Str("4d::7e54:97:2e:ef")],
-            "Separator must be placed after every second character",
+            "misplaced or missing separator at position 3",
         );
     }
 
     #[test]
     fn test_eui64_deserialize_invalid_separator_position() {
-        assert_de_tokens_error::<Eui64>(
+        assert_de_tokens_error::<Readable<Eui64>>(
             &[Token::Str(":4d7e:54:00:00:97:2e:ef")],
-            "Separator must be placed after every second character",
+            "misplaced or missing separator at position 0",
         );
 
-        assert_de_tokens_error::<Eui64>(
+        assert_de_tokens_error::<Readable<Eui64>>(
             &[Token::Str("4d:7e:54:00:00:97:2eef:")],
-            "Separator must be placed after every second character",
+            "misplaced or missing separator at position 20",
         );
 
-        assert_de_tokens_error::<Eui64>(
+        assert_de_tokens_error::<Readable<Eui64>>(
             &[Token::Str("4d::7e54:00:00:97:2e:ef")],
-            "Separator must be placed after every second character",
+            "misplaced or missing separator at position 3",
         );
     }
 
     #[test]
     fn test_eui48_deserialize_different_separators() {
-        assert_de_tokens_error::<Eui48>(
+        assert_de_tokens_error::<Readable<Eui48>>(
             &[Token::Str("4d:7e:54-97:2e:ef")],
-            "Only one type of separator should be used",
+            "only one type of separator should be used",
         );
     }
 
     #[test]
     fn test_eui64_deserialize_different_separators() {
-        assert_de_tokens_error::<Eui64>(
+        assert_de_tokens_error::<Readable<Eui64>>(
             &[Token::Str("4d:7e-54:00:00:97:2e-ef")],
-            "Only one type of separator should be used",
+            "only one type of separator should be used",
+        );
+    }
+
+    #[test]
+    fn test_eui48_deserialize_error_matches_try_from() {
+        extern crate std;
+        use core::convert::TryFrom;
+        use std::string::ToString;
+
+        let input = "4d:7e:54:97:2e:eg";
+        let error = Eui48::try_from(input).err().unwrap();
+
+        assert_de_tokens_error::<Readable<Eui48>>(&[Token::Str(input)], &error.to_string());
+    }
+
+    #[test]
+    fn test_eui48_deserialize_compact() {
+        assert_de_tokens(
+            &Eui48::from(85204980412143).compact(),
+            &[
+                Token::Tuple { len: 6 },
+                Token::U8(0x4d),
+                Token::U8(0x7e),
+                Token::U8(0x54),
+                Token::U8(0x97),
+                Token::U8(0x2e),
+                Token::U8(0xef),
+                Token::TupleEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn test_eui64_deserialize_compact() {
+        assert_de_tokens(
+            &Eui64::from(5583992946972634863).compact(),
+            &[
+                Token::Tuple { len: 8 },
+                Token::U8(0x4d),
+                Token::U8(0x7e),
+                Token::U8(0x54),
+                Token::U8(0x00),
+                Token::U8(0x00),
+                Token::U8(0x97),
+                Token::U8(0x2e),
+                Token::U8(0xef),
+                Token::TupleEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn test_eui48_deserialize_bytes() {
+        let eui48 = Eui48::from(85204980412143);
+        let octets: &'static [u8] = &[0x4d, 0x7e, 0x54, 0x97, 0x2e, 0xef];
+
+        assert_de_tokens(&eui48.readable(), &[Token::Bytes(octets)]);
+        assert_de_tokens(&eui48.readable(), &[Token::BorrowedBytes(octets)]);
+        assert_de_tokens(&eui48.compact(), &[Token::ByteBuf(octets)]);
+    }
+
+    #[test]
+    fn test_eui64_deserialize_bytes() {
+        assert_de_tokens(
+            &Eui64::from(5583992946972634863).compact(),
+            &[Token::Bytes(&[
+                0x4d, 0x7e, 0x54, 0x00, 0x00, 0x97, 0x2e, 0xef,
+            ])],
+        );
+    }
+
+    #[test]
+    fn test_eui48_deserialize_seq() {
+        assert_de_tokens(
+            &Eui48::from(85204980412143).readable(),
+            &[
+                Token::Seq { len: Some(6) },
+                Token::U8(0x4d),
+                Token::U8(0x7e),
+                Token::U8(0x54),
+                Token::U8(0x97),
+                Token::U8(0x2e),
+                Token::U8(0xef),
+                Token::SeqEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn test_eui48_deserialize_invalid_bytes() {
+        assert_de_tokens_error::<Readable<Eui48>>(
+            &[Token::Bytes(&[0x4d, 0x7e, 0x54])],
+            "invalid length 3, expected 12 byte string with only hexadecimal characters, \
+             14 byte string in dotted form or 17 byte string with hexadecimal characters and \
+             separator after every second character, 6 octets or 48 bit integer",
+        );
+        assert_de_tokens_error::<Readable<Eui48>>(
+            &[
+                Token::Seq { len: Some(2) },
+                Token::U8(0x4d),
+                Token::U8(0x7e),
+                Token::SeqEnd,
+            ],
+            "invalid length 2, expected 12 byte string with only hexadecimal characters, \
+             14 byte string in dotted form or 17 byte string with hexadecimal characters and \
+             separator after every second character, 6 octets or 48 bit integer",
+        );
+    }
+
+    #[test]
+    fn test_eui48_deserialize_integer() {
+        let eui48 = Eui48::from(85204980412143);
+
+        assert_de_tokens(&eui48.readable(), &[Token::U64(85204980412143)]);
+        assert_de_tokens(&eui48.readable(), &[Token::I64(85204980412143)]);
+    }
+
+    #[test]
+    fn test_eui64_deserialize_integer() {
+        assert_de_tokens(&Eui64::from(u64::MAX).readable(), &[Token::U64(u64::MAX)]);
+    }
+
+    #[test]
+    fn test_eui48_deserialize_integer_out_of_range() {
+        assert_de_tokens_error::<Readable<Eui48>>(
+            &[Token::U64(0x1_0000_0000_0000)],
+            "invalid value: integer `281474976710656`, expected 12 byte string with only \
+             hexadecimal characters, 14 byte string in dotted form or 17 byte string with \
+             hexadecimal characters and separator after every second character, 6 octets or \
+             48 bit integer",
+        );
+        assert_de_tokens_error::<Readable<Eui48>>(
+            &[Token::I64(-1)],
+            "invalid value: integer `-1`, expected 12 byte string with only hexadecimal \
+             characters, 14 byte string in dotted form or 17 byte string with hexadecimal \
+             characters and separator after every second character, 6 octets or 48 bit integer",
+        );
+    }
+
+    #[test]
+    fn test_wrapper_deserialize() {
+        assert_de_tokens(
+            &LowerColon(Eui48::from(85204980412143)),
+            &[Token::Str("4d:7e:54:97:2e:ef")],
+        );
+        assert_de_tokens(
+            &BareHex(Eui64::from(5583992946972634863)),
+            &[Token::Str("4d7e540000972eef")],
+        );
+    }
+
+    #[test]
+    fn test_eui48_deserialize_in_place() {
+        use serde::de::value::{Error, StrDeserializer, U64Deserializer};
+        use serde::de::IntoDeserializer;
+        use serde::Deserialize;
+
+        let mut eui48 = Eui48::from(0);
+
+        let deserializer: StrDeserializer<Error> = "4d:7e:54:97:2e:ef".into_deserializer();
+        Eui48::deserialize_in_place(deserializer, &mut eui48).unwrap();
+        assert_eq!(eui48, Eui48::from(85204980412143));
+
+        let deserializer: U64Deserializer<Error> = 1u64.into_deserializer();
+        Eui48::deserialize_in_place(deserializer, &mut eui48).unwrap();
+        assert_eq!(eui48, Eui48::from(1));
+
+        let deserializer: U64Deserializer<Error> = u64::MAX.into_deserializer();
+        assert!(Eui48::deserialize_in_place(deserializer, &mut eui48).is_err());
+    }
+
+    #[test]
+    fn test_eui64_deserialize_in_place() {
+        use serde::de::value::{Error, StrDeserializer};
+        use serde::de::IntoDeserializer;
+        use serde::Deserialize;
+
+        let mut eui64 = Eui64::from(0);
+        let deserializer: StrDeserializer<Error> = "4d7e.5400.0097.2eef".into_deserializer();
+
+        Eui64::deserialize_in_place(deserializer, &mut eui64).unwrap();
+        assert_eq!(eui64, Eui64::from(5583992946972634863));
+    }
+
+    #[test]
+    fn test_visitor_composition() {
+        use super::{Eui48Visitor, Eui64Visitor};
+        use serde::de::value::Error;
+        use serde::de::Visitor;
+
+        assert_eq!(
+            Eui48Visitor.visit_str::<Error>("4d:7e:54:97:2e:ef"),
+            Ok(Eui48::from(85204980412143))
+        );
+        assert!(Eui48Visitor.visit_str::<Error>("eth0").is_err());
+        assert_eq!(Eui64Visitor.visit_u64::<Error>(1), Ok(Eui64::from(1)));
+    }
+
+    #[test]
+    fn test_oui_deserialize() {
+        let oui = Oui::new([0x4d, 0x7e, 0x54]);
+
+        assert_de_tokens(&oui.readable(), &[Token::String("4d:7e:54")]);
+        assert_de_tokens(
+            &oui.compact(),
+            &[
+                Token::Tuple { len: 3 },
+                Token::U8(0x4d),
+                Token::U8(0x7e),
+                Token::U8(0x54),
+                Token::TupleEnd,
+            ],
+        );
+        assert_de_tokens_error::<Readable<Oui>>(
+            &[Token::String("4d:7e")],
+            "invalid eui string length 5",
+        );
+    }
+
+    #[test]
+    fn test_block_deserialize() {
+        let block = Eui48Block::new(Eui48::from(85204980412143), 16).unwrap();
+
+        assert_de_tokens(&block.readable(), &[Token::String("4d:7e:54:97:2e:ef+16")]);
+        assert_de_tokens(
+            &block.compact(),
+            &[
+                Token::Tuple { len: 2 },
+                Token::Bytes(&[0x4d, 0x7e, 0x54, 0x97, 0x2e, 0xef]),
+                Token::U64(16),
+                Token::TupleEnd,
+            ],
+        );
+        assert_de_tokens_error::<Readable<Eui48Block>>(
+            &[Token::String("ff:ff:ff:ff:ff:ff+2")],
+            "invalid value: string \"ff:ff:ff:ff:ff:ff+2\", expected string with base eui and \
+             block length separated by plus or tuple of base eui and block length",
+        );
+        assert_de_tokens_error::<Compact<Eui48Block>>(
+            &[
+                Token::Tuple { len: 2 },
+                Token::Bytes(&[0xff; 6]),
+                Token::U64(2),
+                Token::TupleEnd,
+            ],
+            "invalid value: integer `2`, expected block within eui space",
+        );
+    }
+
+    #[test]
+    fn test_mask_deserialize() {
+        let mask = Eui48Mask::from_prefix(Eui48::from(0x4D7E_5400_0000), 24).unwrap();
+
+        assert_de_tokens(&mask.readable(), &[Token::String("4d:7e:54:00:00:00/24")]);
+        assert_de_tokens(
+            &mask.compact(),
+            &[
+                Token::Tuple { len: 2 },
+                Token::Tuple { len: 6 },
+                Token::U8(0x4d),
+                Token::U8(0x7e),
+                Token::U8(0x54),
+                Token::U8(0),
+                Token::U8(0),
+                Token::U8(0),
+                Token::TupleEnd,
+                Token::Tuple { len: 6 },
+                Token::U8(0xff),
+                Token::U8(0xff),
+                Token::U8(0xff),
+                Token::U8(0),
+                Token::U8(0),
+                Token::U8(0),
+                Token::TupleEnd,
+                Token::TupleEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn test_pattern_deserialize() {
+        let pattern = Eui48Pattern::parse("4D:7E:54:*:*:*").unwrap();
+
+        assert_de_tokens(&pattern.readable(), &[Token::String("4d-7e-54-*-*-*")]);
+        assert_de_tokens(
+            &pattern.compact(),
+            &[
+                Token::Tuple { len: 2 },
+                Token::Bytes(&[0x4d, 0x7e, 0x54, 0, 0, 0]),
+                Token::Bytes(&[0xff, 0xff, 0xff, 0, 0, 0]),
+                Token::TupleEnd,
+            ],
+        );
+        assert_de_tokens_error::<Readable<Eui48Pattern>>(
+            &[Token::String("4d-7e-54-*-*")],
+            "expected 6 octets, found 5",
+        );
+        assert_de_tokens_error::<Compact<Eui48Pattern>>(
+            &[
+                Token::Tuple { len: 2 },
+                Token::Bytes(&[0x4d, 0x7e, 0x54, 0, 0, 0]),
+                Token::Bytes(&[0xff, 0xff, 0xfe, 0, 0, 0]),
+                Token::TupleEnd,
+            ],
+            "invalid value: partial digit mask, expected glob-style eui pattern like \
+             4D:7E:54:*:*:* or tuple of pattern and mask covering whole hexadecimal digits",
         );
     }
 }
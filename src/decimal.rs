@@ -0,0 +1,191 @@
+use crate::{Eui, Eui48, Eui64, FormattedEui48, FormattedEui64, StringToEuiError};
+
+macro_rules! to_decimal_string {
+    ($bytes: expr, $formatted: ty) => {{
+        let mut formatted = <$formatted>::empty();
+
+        for (i, &byte) in $bytes.iter().enumerate() {
+            if i != 0 {
+                formatted.push(b'.');
+            }
+
+            if byte >= 100 {
+                formatted.push(b'0' + byte / 100);
+            }
+
+            if byte >= 10 {
+                formatted.push(b'0' + byte / 10 % 10);
+            }
+
+            formatted.push(b'0' + byte % 10);
+        }
+
+        formatted
+    }};
+}
+
+fn decimal_to_eui(input: &str, result: &mut [u8]) -> Result<(), StringToEuiError> {
+    if input.is_empty() {
+        return Err(StringToEuiError::InvalidLength { length: 0 });
+    }
+
+    let mut octets = 0;
+    let mut start = 0;
+
+    for group in input.split('.') {
+        if group.is_empty() {
+            return Err(StringToEuiError::InvalidSeparatorPlace { position: start });
+        }
+
+        if octets == result.len() {
+            return Err(StringToEuiError::TrailingCharacters {
+                position: start - 1,
+            });
+        }
+
+        let mut value: u32 = 0;
+
+        for (i, c) in group.char_indices() {
+            match c.to_digit(10) {
+                Some(digit) => value = value * 10 + digit,
+                None => {
+                    return Err(StringToEuiError::InvalidChar {
+                        char: c,
+                        position: start + i,
+                    })
+                }
+            }
+
+            if value > 0xFF {
+                return Err(StringToEuiError::OctetOutOfRange);
+            }
+        }
+
+        result[octets] = value as u8;
+        octets += 1;
+        start += group.len() + 1;
+    }
+
+    if octets != result.len() {
+        return Err(StringToEuiError::TooFewOctets {
+            octets,
+            expected: result.len(),
+        });
+    }
+
+    Ok(())
+}
+
+impl Eui48 {
+    /// Returns SNMP style dotted decimal form (`77.126.84.151.46.239`).
+    pub fn to_decimal_string(&self) -> FormattedEui48 {
+        to_decimal_string!(self.0, FormattedEui48)
+    }
+
+    /// Parses SNMP style dotted decimal form.
+    pub fn parse_decimal(input: &str) -> Result<Self, StringToEuiError> {
+        let mut result = [0; 6];
+        decimal_to_eui(input, &mut result)?;
+
+        Ok(Eui(result))
+    }
+}
+
+impl Eui64 {
+    /// Returns SNMP style dotted decimal form (`77.126.84.0.0.151.46.239`).
+    pub fn to_decimal_string(&self) -> FormattedEui64 {
+        to_decimal_string!(self.0, FormattedEui64)
+    }
+
+    /// Parses SNMP style dotted decimal form.
+    pub fn parse_decimal(input: &str) -> Result<Self, StringToEuiError> {
+        let mut result = [0; 8];
+        decimal_to_eui(input, &mut result)?;
+
+        Ok(Eui(result))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Eui48, Eui64, StringToEuiError};
+
+    #[test]
+    fn test_eui48_to_decimal_string() {
+        assert_eq!(
+            Eui48::from(85204980412143).to_decimal_string(),
+            "77.126.84.151.46.239"
+        );
+        assert_eq!(Eui48::from(0).to_decimal_string(), "0.0.0.0.0.0");
+        assert_eq!(
+            Eui48::from(0xFFFF_FFFF_FFFF).to_decimal_string(),
+            "255.255.255.255.255.255"
+        );
+    }
+
+    #[test]
+    fn test_eui64_to_decimal_string() {
+        assert_eq!(
+            Eui64::from(5583992946972634863).to_decimal_string(),
+            "77.126.84.0.0.151.46.239"
+        );
+        assert_eq!(
+            Eui64::from(u64::MAX).to_decimal_string(),
+            "255.255.255.255.255.255.255.255"
+        );
+    }
+
+    #[test]
+    fn test_eui48_parse_decimal() {
+        assert_eq!(
+            Eui48::parse_decimal("77.126.84.151.46.239"),
+            Ok(Eui48::from(85204980412143))
+        );
+        assert_eq!(
+            Eui48::parse_decimal("077.126.084.151.046.239"),
+            Ok(Eui48::from(85204980412143))
+        );
+    }
+
+    #[test]
+    fn test_eui64_parse_decimal() {
+        assert_eq!(
+            Eui64::parse_decimal("77.126.84.0.0.151.46.239"),
+            Ok(Eui64::from(5583992946972634863))
+        );
+    }
+
+    #[test]
+    fn test_eui48_parse_decimal_invalid() {
+        assert_eq!(
+            Eui48::parse_decimal(""),
+            Err(StringToEuiError::InvalidLength { length: 0 })
+        );
+        assert_eq!(
+            Eui48::parse_decimal("77.126.84.151.46"),
+            Err(StringToEuiError::TooFewOctets {
+                octets: 5,
+                expected: 6
+            })
+        );
+        assert_eq!(
+            Eui48::parse_decimal("77.126.84.151.46.239.1"),
+            Err(StringToEuiError::TrailingCharacters { position: 20 })
+        );
+        assert_eq!(
+            Eui48::parse_decimal("77.126..151.46.239"),
+            Err(StringToEuiError::InvalidSeparatorPlace { position: 7 })
+        );
+        assert_eq!(
+            Eui48::parse_decimal("77.126.84.151.46.2a9"),
+            Err(StringToEuiError::InvalidChar {
+                char: 'a',
+                position: 18
+            })
+        );
+        assert_eq!(
+            Eui48::parse_decimal("77.126.84.151.46.256"),
+            Err(StringToEuiError::OctetOutOfRange)
+        );
+    }
+}
@@ -0,0 +1,61 @@
+use crate::{Eui, Eui48, LOCAL_BIT, MULTICAST_BIT};
+use core::hash::Hasher;
+
+impl Eui48 {
+    /// Derives locally administered unicast address from chip unique id, e.g. silicon serial
+    /// number, using the lower 48 bits of hash.
+    ///
+    /// Same id and hasher always give the same address.
+    pub fn from_unique_id<H: Hasher>(id: &[u8], mut hasher: H) -> Self {
+        hasher.write(id);
+
+        let mut octets = [0; 6];
+        octets.copy_from_slice(&hasher.finish().to_be_bytes()[2..]);
+        octets[0] = (octets[0] | LOCAL_BIT) & !MULTICAST_BIT;
+
+        Eui(octets)
+    }
+
+    /// Returns address of `n`-th interface derived from base address, as done by vendors like
+    /// Espressif for Wi-Fi station, access point, Bluetooth and Ethernet interfaces.
+    ///
+    /// Offset is added to NIC specific octets with carry between them, wrapping around
+    /// within the same OUI.
+    pub fn derive_interface(&self, n: u8) -> Eui48 {
+        let nic = u32::from_be_bytes([0, self.0[3], self.0[4], self.0[5]]);
+        let nic = nic.wrapping_add(n as u32).to_be_bytes();
+
+        Eui([self.0[0], self.0[1], self.0[2], nic[1], nic[2], nic[3]])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::fnv::Fnv;
+    use crate::Eui48;
+
+    #[test]
+    fn test_from_unique_id() {
+        let id = [
+            0x30, 0x39, 0x47, 0x10, 0x00, 0x2a, 0x00, 0x19, 0x33, 0x50, 0x4e, 0x31,
+        ];
+        let eui48 = Eui48::from_unique_id(&id, Fnv::default());
+
+        assert_eq!(eui48.0[0] & 0x03, 0x02);
+        assert_eq!(eui48, Eui48::from_unique_id(&id, Fnv::default()));
+        assert_ne!(eui48, Eui48::from_unique_id(&id[1..], Fnv::default()));
+    }
+
+    #[test]
+    fn test_derive_interface() {
+        let base = Eui48::from(0x2462_AB00_00FE);
+
+        assert_eq!(base.derive_interface(0), base);
+        assert_eq!(base.derive_interface(1), Eui48::from(0x2462_AB00_00FF));
+        assert_eq!(base.derive_interface(3), Eui48::from(0x2462_AB00_0101));
+        assert_eq!(
+            Eui48::from(0x2462_ABFF_FFFF).derive_interface(2),
+            Eui48::from(0x2462_AB00_0001)
+        );
+    }
+}
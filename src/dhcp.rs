@@ -0,0 +1,280 @@
+use crate::{Eui, Eui48};
+use core::convert::TryFrom;
+use core::fmt::{Display, Error, Formatter};
+
+/// Hardware type of ethernet as assigned by IANA.
+pub const HARDWARE_TYPE_ETHERNET: u16 = 1;
+
+const DUID_TYPE_LLT: u16 = 1;
+const DUID_TYPE_LL: u16 = 3;
+
+/// DHCPv6 unique identifier based on link-layer address as described in RFC 8415.
+#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+pub enum Duid {
+    /// DUID-LL.
+    LinkLayer { hardware_type: u16, eui48: Eui48 },
+    /// DUID-LLT, time is in seconds since midnight (UTC), January 1, 2000, modulo 2^32.
+    LinkLayerTime {
+        hardware_type: u16,
+        time: u32,
+        eui48: Eui48,
+    },
+}
+
+/// Possible errors while encoding or decoding duid.
+#[derive(Debug, PartialEq, Eq)]
+pub enum DuidError {
+    BufferTooSmall { required: usize },
+    InvalidLength { length: usize },
+    UnsupportedType { duid_type: u16 },
+}
+
+impl Display for DuidError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        match self {
+            DuidError::BufferTooSmall { required } => {
+                write!(f, "buffer too small, {} bytes required", required)
+            }
+            DuidError::InvalidLength { length } => write!(f, "invalid duid length {}", length),
+            DuidError::UnsupportedType { duid_type } => {
+                write!(f, "unsupported duid type {}", duid_type)
+            }
+        }
+    }
+}
+
+impl core::error::Error for DuidError {}
+
+impl Duid {
+    /// Number of bytes written by `encode`.
+    pub fn encoded_len(&self) -> usize {
+        match self {
+            Duid::LinkLayer { .. } => 10,
+            Duid::LinkLayerTime { .. } => 14,
+        }
+    }
+
+    /// Encodes duid into buffer and returns number of bytes written.
+    pub fn encode(&self, buf: &mut [u8]) -> Result<usize, DuidError> {
+        let required = self.encoded_len();
+
+        if buf.len() < required {
+            return Err(DuidError::BufferTooSmall { required });
+        }
+
+        match *self {
+            Duid::LinkLayer {
+                hardware_type,
+                eui48,
+            } => {
+                buf[0..2].copy_from_slice(&DUID_TYPE_LL.to_be_bytes());
+                buf[2..4].copy_from_slice(&hardware_type.to_be_bytes());
+                buf[4..10].copy_from_slice(&eui48.0);
+            }
+            Duid::LinkLayerTime {
+                hardware_type,
+                time,
+                eui48,
+            } => {
+                buf[0..2].copy_from_slice(&DUID_TYPE_LLT.to_be_bytes());
+                buf[2..4].copy_from_slice(&hardware_type.to_be_bytes());
+                buf[4..8].copy_from_slice(&time.to_be_bytes());
+                buf[8..14].copy_from_slice(&eui48.0);
+            }
+        }
+
+        Ok(required)
+    }
+}
+
+impl Eui48 {
+    /// Encodes DHCPv4 client identifier (option 61) payload: hardware type followed by address.
+    pub fn to_client_identifier(&self) -> [u8; 7] {
+        let mut result = [0; 7];
+        result[0] = HARDWARE_TYPE_ETHERNET as u8;
+        result[1..].copy_from_slice(&self.0);
+
+        result
+    }
+
+    /// Decodes DHCPv4 client identifier (option 61) payload with ethernet hardware type.
+    pub fn from_client_identifier(data: &[u8]) -> Option<Self> {
+        if data.len() != 7 || data[0] != HARDWARE_TYPE_ETHERNET as u8 {
+            return None;
+        }
+
+        Some(read_eui48(&data[1..]))
+    }
+
+    /// Returns zero padded DHCPv4 `chaddr` field.
+    pub fn to_chaddr(&self) -> [u8; 16] {
+        let mut result = [0; 16];
+        result[..6].copy_from_slice(&self.0);
+
+        result
+    }
+
+    /// Reads address from DHCPv4 `chaddr` field with hardware address length of 6.
+    pub fn from_chaddr(chaddr: &[u8; 16]) -> Self {
+        read_eui48(&chaddr[..6])
+    }
+}
+
+fn read_eui48(data: &[u8]) -> Eui48 {
+    let mut result = [0; 6];
+    result.copy_from_slice(data);
+
+    Eui(result)
+}
+
+impl TryFrom<&[u8]> for Duid {
+    type Error = DuidError;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        if value.len() < 2 {
+            return Err(DuidError::InvalidLength {
+                length: value.len(),
+            });
+        }
+
+        let duid_type = u16::from_be_bytes([value[0], value[1]]);
+
+        match duid_type {
+            DUID_TYPE_LL if value.len() == 10 => Ok(Duid::LinkLayer {
+                hardware_type: u16::from_be_bytes([value[2], value[3]]),
+                eui48: read_eui48(&value[4..10]),
+            }),
+            DUID_TYPE_LLT if value.len() == 14 => Ok(Duid::LinkLayerTime {
+                hardware_type: u16::from_be_bytes([value[2], value[3]]),
+                time: u32::from_be_bytes([value[4], value[5], value[6], value[7]]),
+                eui48: read_eui48(&value[8..14]),
+            }),
+            DUID_TYPE_LL | DUID_TYPE_LLT => Err(DuidError::InvalidLength {
+                length: value.len(),
+            }),
+            _ => Err(DuidError::UnsupportedType { duid_type }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Duid, DuidError, Eui48, HARDWARE_TYPE_ETHERNET};
+    use core::convert::TryFrom;
+
+    extern crate std;
+    use std::string::ToString;
+
+    #[test]
+    fn test_duid_error_display() {
+        assert_eq!(
+            DuidError::BufferTooSmall { required: 10 }.to_string(),
+            "buffer too small, 10 bytes required"
+        );
+        assert_eq!(
+            DuidError::UnsupportedType { duid_type: 2 }.to_string(),
+            "unsupported duid type 2"
+        );
+    }
+
+    #[test]
+    fn test_duid_ll_encode() {
+        let duid = Duid::LinkLayer {
+            hardware_type: HARDWARE_TYPE_ETHERNET,
+            eui48: Eui48::from(85204980412143),
+        };
+
+        let mut buf = [0; 16];
+        assert_eq!(duid.encode(&mut buf), Ok(10));
+        assert_eq!(
+            buf[..10],
+            [0x00, 0x03, 0x00, 0x01, 0x4D, 0x7E, 0x54, 0x97, 0x2E, 0xEF]
+        );
+        assert_eq!(Duid::try_from(&buf[..10]), Ok(duid));
+    }
+
+    #[test]
+    fn test_duid_llt_encode() {
+        let duid = Duid::LinkLayerTime {
+            hardware_type: HARDWARE_TYPE_ETHERNET,
+            time: 0x1234_5678,
+            eui48: Eui48::from(85204980412143),
+        };
+
+        let mut buf = [0; 14];
+        assert_eq!(duid.encode(&mut buf), Ok(14));
+        assert_eq!(
+            buf,
+            [0x00, 0x01, 0x00, 0x01, 0x12, 0x34, 0x56, 0x78, 0x4D, 0x7E, 0x54, 0x97, 0x2E, 0xEF]
+        );
+        assert_eq!(Duid::try_from(&buf[..]), Ok(duid));
+    }
+
+    #[test]
+    fn test_duid_encode_buffer_too_small() {
+        let duid = Duid::LinkLayer {
+            hardware_type: HARDWARE_TYPE_ETHERNET,
+            eui48: Eui48::from(85204980412143),
+        };
+
+        assert_eq!(
+            duid.encode(&mut [0; 9]),
+            Err(DuidError::BufferTooSmall { required: 10 })
+        );
+    }
+
+    #[test]
+    fn test_duid_try_from_invalid() {
+        assert_eq!(
+            Duid::try_from(&[0x00][..]),
+            Err(DuidError::InvalidLength { length: 1 })
+        );
+
+        assert_eq!(
+            Duid::try_from(&[0x00, 0x03, 0x00, 0x01, 0x4D][..]),
+            Err(DuidError::InvalidLength { length: 5 })
+        );
+
+        assert_eq!(
+            Duid::try_from(&[0x00, 0x02, 0x00, 0x00][..]),
+            Err(DuidError::UnsupportedType { duid_type: 2 })
+        );
+    }
+
+    #[test]
+    fn test_eui48_client_identifier() {
+        let eui48 = Eui48::from(85204980412143);
+        let client_identifier = eui48.to_client_identifier();
+
+        assert_eq!(
+            client_identifier,
+            [0x01, 0x4D, 0x7E, 0x54, 0x97, 0x2E, 0xEF]
+        );
+        assert_eq!(
+            Eui48::from_client_identifier(&client_identifier),
+            Some(eui48)
+        );
+    }
+
+    #[test]
+    fn test_eui48_from_client_identifier_invalid() {
+        assert_eq!(
+            Eui48::from_client_identifier(&[0x06, 0x4D, 0x7E, 0x54, 0x97, 0x2E, 0xEF]),
+            None
+        );
+        assert_eq!(
+            Eui48::from_client_identifier(&[0x01, 0x4D, 0x7E, 0x54, 0x97, 0x2E]),
+            None
+        );
+    }
+
+    #[test]
+    fn test_eui48_chaddr() {
+        let eui48 = Eui48::from(85204980412143);
+        let chaddr = eui48.to_chaddr();
+
+        assert_eq!(chaddr[..6], [0x4D, 0x7E, 0x54, 0x97, 0x2E, 0xEF]);
+        assert_eq!(chaddr[6..], [0; 10]);
+        assert_eq!(Eui48::from_chaddr(&chaddr), eui48);
+    }
+}
@@ -0,0 +1,69 @@
+use crate::{Eui, Eui48, EuiFormat};
+use ::eui48::{MacAddress, MacAddressFormat};
+use core::convert::TryFrom;
+
+impl From<MacAddress> for Eui48 {
+    fn from(address: MacAddress) -> Self {
+        Eui(address.to_array())
+    }
+}
+
+impl From<Eui48> for MacAddress {
+    fn from(eui48: Eui48) -> Self {
+        MacAddress::new(eui48.0)
+    }
+}
+
+/// Maps `eui48` display formats to the same textual form, `Hexadecimal` (`0x4d7e54972eef`)
+/// has no equivalent and is returned as error.
+impl TryFrom<MacAddressFormat> for EuiFormat {
+    type Error = MacAddressFormat;
+
+    fn try_from(format: MacAddressFormat) -> Result<Self, Self::Error> {
+        match format {
+            MacAddressFormat::Canonical => Ok(EuiFormat::CANONICAL_LOWER),
+            MacAddressFormat::HexString => Ok(EuiFormat::COLON_LOWER),
+            MacAddressFormat::DotNotation => Ok(EuiFormat::DOTTED),
+            format => Err(format),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Eui48, EuiFormat};
+    use ::eui48::{MacAddress, MacAddressFormat};
+    use core::convert::TryFrom;
+
+    #[test]
+    fn test_mac_address_conversion() {
+        let eui48 = Eui48::from(85204980412143);
+        let address = MacAddress::new([0x4d, 0x7e, 0x54, 0x97, 0x2e, 0xef]);
+
+        assert_eq!(MacAddress::from(eui48), address);
+        assert_eq!(Eui48::from(address), eui48);
+    }
+
+    #[test]
+    fn test_mac_address_format() {
+        let eui48 = Eui48::from(85204980412143);
+        let address = MacAddress::from(eui48);
+
+        for format in [
+            MacAddressFormat::Canonical,
+            MacAddressFormat::HexString,
+            MacAddressFormat::DotNotation,
+        ] {
+            let eui_format = EuiFormat::try_from(format).unwrap();
+            assert_eq!(
+                eui48.format_with(eui_format),
+                address.to_string(format).as_str()
+            );
+        }
+
+        assert_eq!(
+            EuiFormat::try_from(MacAddressFormat::Hexadecimal),
+            Err(MacAddressFormat::Hexadecimal)
+        );
+    }
+}
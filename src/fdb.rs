@@ -0,0 +1,192 @@
+use crate::{Eui48, MULTICAST_BIT};
+
+struct Entry<V> {
+    eui: Eui48,
+    value: V,
+    learned: u64,
+}
+
+/// Fixed-capacity L2 learning table mapping source addresses to values like switch ports,
+/// with entries aged by caller supplied ticks.
+///
+/// # Example
+///
+/// ```rust
+/// use eui::{Eui48, FdbTable};
+///
+/// let mut table: FdbTable<u8, 64> = FdbTable::new(300);
+/// table.learn(Eui48::from(0x4C7E_5497_2EEF), 1, 0).unwrap();
+///
+/// assert_eq!(table.lookup(&Eui48::from(0x4C7E_5497_2EEF)), Some(&1));
+///
+/// table.expire(301);
+/// assert_eq!(table.lookup(&Eui48::from(0x4C7E_5497_2EEF)), None);
+/// ```
+pub struct FdbTable<V, const N: usize> {
+    entries: [Option<Entry<V>>; N],
+    max_age: u64,
+}
+
+impl<V, const N: usize> FdbTable<V, N> {
+    /// Creates empty table where entries expire `max_age` ticks after being learned.
+    pub fn new(max_age: u64) -> Self {
+        FdbTable {
+            entries: core::array::from_fn(|_| None),
+            max_age,
+        }
+    }
+
+    /// Returns number of learned addresses.
+    pub fn len(&self) -> usize {
+        self.entries.iter().flatten().count()
+    }
+
+    /// Returns `true` if no addresses are learned.
+    pub fn is_empty(&self) -> bool {
+        self.entries.iter().all(Option::is_none)
+    }
+
+    /// Records that `eui` was seen behind `value` at tick `now`, refreshing existing entry.
+    ///
+    /// When table is full the oldest entry is replaced and returned. Multicast addresses are
+    /// never learned and, like any value for zero capacity table, given back as error.
+    pub fn learn(&mut self, eui: Eui48, value: V, now: u64) -> Result<Option<(Eui48, V)>, V> {
+        if eui.0[0] & MULTICAST_BIT != 0 {
+            return Err(value);
+        }
+
+        if let Some(entry) = self.entries.iter_mut().flatten().find(|e| e.eui == eui) {
+            entry.value = value;
+            entry.learned = now;
+            return Ok(None);
+        }
+
+        // Free slots sort before any learned entry.
+        let slot = self
+            .entries
+            .iter_mut()
+            .min_by_key(|slot| slot.as_ref().map(|e| e.learned));
+
+        match slot {
+            Some(slot) => {
+                let entry = Entry {
+                    eui,
+                    value,
+                    learned: now,
+                };
+
+                Ok(slot.replace(entry).map(|e| (e.eui, e.value)))
+            }
+            None => Err(value),
+        }
+    }
+
+    /// Returns value learned for eui.
+    pub fn lookup(&self, eui: &Eui48) -> Option<&V> {
+        self.entries
+            .iter()
+            .flatten()
+            .find(|entry| entry.eui == *eui)
+            .map(|entry| &entry.value)
+    }
+
+    /// Returns tick at which eui was last learned.
+    pub fn learned_at(&self, eui: &Eui48) -> Option<u64> {
+        self.entries
+            .iter()
+            .flatten()
+            .find(|entry| entry.eui == *eui)
+            .map(|entry| entry.learned)
+    }
+
+    /// Removes eui, returning its value.
+    pub fn remove(&mut self, eui: &Eui48) -> Option<V> {
+        self.entries
+            .iter_mut()
+            .find(|slot| slot.as_ref().is_some_and(|entry| entry.eui == *eui))
+            .and_then(Option::take)
+            .map(|entry| entry.value)
+    }
+
+    /// Removes entries older than `max_age` ticks at tick `now`, returning number removed.
+    pub fn expire(&mut self, now: u64) -> usize {
+        let max_age = self.max_age;
+        let mut removed = 0;
+
+        for slot in self.entries.iter_mut() {
+            let expired = slot
+                .as_ref()
+                .is_some_and(|entry| now.saturating_sub(entry.learned) > max_age);
+
+            if expired {
+                *slot = None;
+                removed += 1;
+            }
+        }
+
+        removed
+    }
+
+    /// Removes entries pointing to value, e.g. when port goes down.
+    pub fn flush(&mut self, value: &V)
+    where
+        V: PartialEq,
+    {
+        for slot in self.entries.iter_mut() {
+            if slot.as_ref().is_some_and(|entry| entry.value == *value) {
+                *slot = None;
+            }
+        }
+    }
+
+    /// Returns iterator over learned euis and their values.
+    pub fn iter(&self) -> impl Iterator<Item = (&Eui48, &V)> {
+        self.entries
+            .iter()
+            .flatten()
+            .map(|entry| (&entry.eui, &entry.value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Eui48, FdbTable};
+
+    #[test]
+    fn test_fdb_learn() {
+        let mut table: FdbTable<u8, 2> = FdbTable::new(10);
+        let first = Eui48::from(0x4C7E_5497_2EEF);
+        let second = Eui48::from(2);
+
+        assert_eq!(table.learn(first, 1, 0), Ok(None));
+        assert_eq!(table.learn(second, 2, 1), Ok(None));
+        assert_eq!(table.learn(first, 3, 2), Ok(None));
+        assert_eq!(table.lookup(&first), Some(&3));
+        assert_eq!(table.learned_at(&first), Some(2));
+
+        assert_eq!(table.learn(Eui48::from(4), 4, 3), Ok(Some((second, 2))));
+        assert_eq!(table.learn(Eui48::from(0x0100_5E00_0001), 5, 3), Err(5));
+        assert_eq!(table.len(), 2);
+    }
+
+    #[test]
+    fn test_fdb_expire() {
+        let mut table: FdbTable<u8, 4> = FdbTable::new(10);
+
+        table.learn(Eui48::from(1), 1, 0).unwrap();
+        table.learn(Eui48::from(2), 1, 5).unwrap();
+        table.learn(Eui48::from(3), 2, 5).unwrap();
+
+        assert_eq!(table.expire(10), 0);
+        assert_eq!(table.expire(11), 1);
+        assert_eq!(table.lookup(&Eui48::from(1)), None);
+
+        table.flush(&1);
+        assert_eq!(
+            table.iter().collect::<heapless::Vec<_, 4>>(),
+            [(&Eui48::from(3), &2)]
+        );
+        assert_eq!(table.remove(&Eui48::from(3)), Some(2));
+        assert!(table.is_empty());
+    }
+}
@@ -0,0 +1,34 @@
+use crate::Prf;
+use core::hash::Hasher;
+
+/// FNV-1a, good enough to check plumbing but not a real PRF.
+pub(crate) struct Fnv(u64);
+
+impl Default for Fnv {
+    fn default() -> Self {
+        Fnv(0xcbf29ce484222325)
+    }
+}
+
+impl Hasher for Fnv {
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 ^= byte as u64;
+            self.0 = self.0.wrapping_mul(0x100000001b3);
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+impl Prf for Fnv {
+    fn update(&mut self, data: &[u8]) {
+        self.write(data);
+    }
+
+    fn finish(self) -> u64 {
+        Hasher::finish(&self)
+    }
+}
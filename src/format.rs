@@ -0,0 +1,479 @@
+use crate::{Eui48, Eui64};
+use core::fmt;
+use core::ops::Deref;
+
+const UPPERCASE_HEX_CHARS: &[u8] = b"0123456789ABCDEF";
+const LOWERCASE_HEX_CHARS: &[u8] = b"0123456789abcdef";
+
+/// Character placed between groups of octets.
+#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+pub enum Separator {
+    Dash,
+    Colon,
+    Dot,
+    Space,
+    None,
+}
+
+/// Case of hexadecimal digits.
+#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+pub enum Case {
+    Upper,
+    Lower,
+}
+
+/// Options describing textual form of eui.
+///
+/// `group_size` is number of octets between separators, e.g. `2` for `4d7e.5497.2eef`.
+#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+pub struct EuiFormat {
+    pub separator: Separator,
+    pub group_size: usize,
+    pub case: Case,
+}
+
+impl EuiFormat {
+    /// `4D-7E-54-97-2E-EF`
+    pub const CANONICAL: EuiFormat = EuiFormat {
+        separator: Separator::Dash,
+        group_size: 1,
+        case: Case::Upper,
+    };
+
+    /// `4d-7e-54-97-2e-ef`
+    pub const CANONICAL_LOWER: EuiFormat = EuiFormat {
+        separator: Separator::Dash,
+        group_size: 1,
+        case: Case::Lower,
+    };
+
+    /// `4D:7E:54:97:2E:EF`
+    pub const COLON: EuiFormat = EuiFormat {
+        separator: Separator::Colon,
+        group_size: 1,
+        case: Case::Upper,
+    };
+
+    /// `4d:7e:54:97:2e:ef`
+    pub const COLON_LOWER: EuiFormat = EuiFormat {
+        separator: Separator::Colon,
+        group_size: 1,
+        case: Case::Lower,
+    };
+
+    /// `4d7e.5497.2eef`
+    pub const DOTTED: EuiFormat = EuiFormat {
+        separator: Separator::Dot,
+        group_size: 2,
+        case: Case::Lower,
+    };
+
+    /// `4D7E54972EEF`
+    pub const BARE: EuiFormat = EuiFormat {
+        separator: Separator::None,
+        group_size: 1,
+        case: Case::Upper,
+    };
+
+    /// `4d7e54972eef`
+    pub const BARE_LOWER: EuiFormat = EuiFormat {
+        separator: Separator::None,
+        group_size: 1,
+        case: Case::Lower,
+    };
+}
+
+impl Default for EuiFormat {
+    fn default() -> Self {
+        EuiFormat::CANONICAL
+    }
+}
+
+impl Separator {
+    fn as_byte(self) -> Option<u8> {
+        match self {
+            Separator::Dash => Some(b'-'),
+            Separator::Colon => Some(b':'),
+            Separator::Dot => Some(b'.'),
+            Separator::Space => Some(b' '),
+            Separator::None => None,
+        }
+    }
+
+    pub(crate) fn from_char(c: char) -> Option<Separator> {
+        match c {
+            '-' => Some(Separator::Dash),
+            ':' => Some(Separator::Colon),
+            '.' => Some(Separator::Dot),
+            ' ' => Some(Separator::Space),
+            _ => None,
+        }
+    }
+}
+
+/// Error returned when caller provided buffer can't hold formatted eui.
+#[derive(Debug, PartialEq, Eq)]
+pub struct BufferTooSmall {
+    pub required: usize,
+}
+
+impl fmt::Display for BufferTooSmall {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "buffer too small, {} bytes required", self.required)
+    }
+}
+
+impl core::error::Error for BufferTooSmall {}
+
+fn formatted_len(octets: usize, format: EuiFormat) -> usize {
+    let separators = match format.separator {
+        Separator::None => 0,
+        _ if format.group_size == 0 => 0,
+        _ => (octets.max(1) - 1) / format.group_size,
+    };
+
+    octets * 2 + separators
+}
+
+/// Calls `push` for every character of eui in requested format.
+pub(crate) fn write_hex<F: FnMut(u8)>(bytes: &[u8], format: EuiFormat, mut push: F) {
+    let hex_chars = match format.case {
+        Case::Upper => UPPERCASE_HEX_CHARS,
+        Case::Lower => LOWERCASE_HEX_CHARS,
+    };
+
+    for (i, &byte) in bytes.iter().enumerate() {
+        if let Some(separator) = format.separator.as_byte() {
+            if i != 0 && format.group_size != 0 && i % format.group_size == 0 {
+                push(separator);
+            }
+        }
+
+        push(hex_chars[(byte >> 4) as usize]);
+        push(hex_chars[(byte & 0xf) as usize]);
+    }
+}
+
+macro_rules! formatted_eui {
+    ($name: ident, $capacity: expr, $description: expr) => {
+        #[doc = $description]
+        #[derive(Eq, PartialEq, Copy, Clone, Hash)]
+        pub struct $name {
+            buf: [u8; $capacity],
+            len: u8,
+        }
+
+        impl $name {
+            pub(crate) fn new(bytes: &[u8], format: EuiFormat) -> Self {
+                let mut formatted = Self::empty();
+                write_hex(bytes, format, |c| formatted.push(c));
+
+                formatted
+            }
+
+            pub(crate) fn empty() -> Self {
+                $name {
+                    buf: [0; $capacity],
+                    len: 0,
+                }
+            }
+
+            pub(crate) fn push(&mut self, c: u8) {
+                self.buf[self.len as usize] = c;
+                self.len += 1;
+            }
+
+            #[inline]
+            pub fn as_str(&self) -> &str {
+                unsafe { core::str::from_utf8_unchecked(&self.buf[..self.len as usize]) }
+            }
+        }
+
+        impl Deref for $name {
+            type Target = str;
+
+            fn deref(&self) -> &str {
+                self.as_str()
+            }
+        }
+
+        impl AsRef<str> for $name {
+            fn as_ref(&self) -> &str {
+                self.as_str()
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.pad(self.as_str())
+            }
+        }
+
+        impl fmt::Debug for $name {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                fmt::Debug::fmt(self.as_str(), f)
+            }
+        }
+
+        impl PartialEq<str> for $name {
+            fn eq(&self, other: &str) -> bool {
+                self.as_str() == other
+            }
+        }
+
+        impl PartialEq<&str> for $name {
+            fn eq(&self, other: &&str) -> bool {
+                self.as_str() == *other
+            }
+        }
+
+        #[cfg(feature = "zeroize")]
+        impl zeroize::Zeroize for $name {
+            fn zeroize(&mut self) {
+                self.buf.zeroize();
+                self.len.zeroize();
+            }
+        }
+    };
+}
+
+formatted_eui!(
+    FormattedEui48,
+    23,
+    "Textual form of `Eui48` stored on stack."
+);
+formatted_eui!(
+    FormattedEui64,
+    31,
+    "Textual form of `Eui64` stored on stack."
+);
+
+pub(crate) fn format_into<'a>(
+    bytes: &[u8],
+    format: EuiFormat,
+    buf: &'a mut [u8],
+) -> Result<&'a str, BufferTooSmall> {
+    let required = formatted_len(bytes.len(), format);
+
+    if buf.len() < required {
+        return Err(BufferTooSmall { required });
+    }
+
+    let mut position = 0;
+
+    write_hex(bytes, format, |c| {
+        buf[position] = c;
+        position += 1;
+    });
+
+    Ok(unsafe { core::str::from_utf8_unchecked(&buf[..required]) })
+}
+
+pub(crate) fn write_to<W: fmt::Write>(bytes: &[u8], format: EuiFormat, w: &mut W) -> fmt::Result {
+    let mut result = Ok(());
+
+    write_hex(bytes, format, |c| {
+        if result.is_ok() {
+            result = w.write_char(c as char);
+        }
+    });
+
+    result
+}
+
+impl Eui48 {
+    /// Writes canonical form into buffer without intermediate string.
+    #[inline]
+    pub fn format_into<'a>(&self, buf: &'a mut [u8]) -> Result<&'a str, BufferTooSmall> {
+        format_into(&self.0, EuiFormat::CANONICAL, buf)
+    }
+
+    /// Streams eui in requested format into writer.
+    #[inline]
+    pub fn write_to<W: fmt::Write>(&self, w: &mut W, format: EuiFormat) -> fmt::Result {
+        write_to(&self.0, format, w)
+    }
+
+    /// Returns eui in requested format.
+    #[inline]
+    pub fn format_with(&self, format: EuiFormat) -> FormattedEui48 {
+        FormattedEui48::new(&self.0, format)
+    }
+}
+
+impl Eui64 {
+    /// Writes canonical form into buffer without intermediate string.
+    #[inline]
+    pub fn format_into<'a>(&self, buf: &'a mut [u8]) -> Result<&'a str, BufferTooSmall> {
+        format_into(&self.0, EuiFormat::CANONICAL, buf)
+    }
+
+    /// Streams eui in requested format into writer.
+    #[inline]
+    pub fn write_to<W: fmt::Write>(&self, w: &mut W, format: EuiFormat) -> fmt::Result {
+        write_to(&self.0, format, w)
+    }
+
+    /// Returns eui in requested format.
+    #[inline]
+    pub fn format_with(&self, format: EuiFormat) -> FormattedEui64 {
+        FormattedEui64::new(&self.0, format)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        BufferTooSmall, Case, Eui48, Eui64, EuiFormat, FormattedEui48, FormattedEui64, Separator,
+    };
+
+    #[test]
+    fn test_eui48_format_with() {
+        let eui48 = Eui48::from(85204980412143);
+
+        assert_eq!(eui48.format_with(EuiFormat::default()), "4D-7E-54-97-2E-EF");
+        assert_eq!(
+            eui48.format_with(EuiFormat::COLON_LOWER),
+            "4d:7e:54:97:2e:ef"
+        );
+        assert_eq!(eui48.format_with(EuiFormat::DOTTED), "4d7e.5497.2eef");
+        assert_eq!(eui48.format_with(EuiFormat::BARE), "4D7E54972EEF");
+
+        let format = EuiFormat {
+            separator: Separator::Colon,
+            group_size: 2,
+            case: Case::Upper,
+        };
+        assert_eq!(eui48.format_with(format), "4D7E:5497:2EEF");
+
+        let format = EuiFormat {
+            separator: Separator::Dash,
+            group_size: 3,
+            case: Case::Lower,
+        };
+        assert_eq!(eui48.format_with(format), "4d7e54-972eef");
+    }
+
+    #[test]
+    fn test_eui64_format_with() {
+        let eui64 = Eui64::from(5583992946972634863);
+
+        assert_eq!(
+            eui64.format_with(EuiFormat::default()),
+            "4D-7E-54-00-00-97-2E-EF"
+        );
+        assert_eq!(
+            eui64.format_with(EuiFormat::COLON_LOWER),
+            "4d:7e:54:00:00:97:2e:ef"
+        );
+        assert_eq!(eui64.format_with(EuiFormat::DOTTED), "4d7e.5400.0097.2eef");
+        assert_eq!(eui64.format_with(EuiFormat::BARE), "4D7E540000972EEF");
+    }
+
+    #[test]
+    fn test_format_with_zero_group_size() {
+        let format = EuiFormat {
+            separator: Separator::Dash,
+            group_size: 0,
+            case: Case::Upper,
+        };
+
+        assert_eq!(
+            Eui48::from(85204980412143).format_with(format),
+            "4D7E54972EEF"
+        );
+    }
+
+    #[test]
+    fn test_eui48_format_into() {
+        let mut buf = [0; 20];
+        let eui48 = Eui48::from(85204980412143);
+
+        assert_eq!(eui48.format_into(&mut buf), Ok("4D-7E-54-97-2E-EF"));
+        assert_eq!(buf[17..], [0; 3]);
+        assert_eq!(
+            eui48.format_into(&mut [0; 16]),
+            Err(BufferTooSmall { required: 17 })
+        );
+    }
+
+    #[test]
+    fn test_eui64_format_into() {
+        let mut buf = [0; 23];
+        let eui64 = Eui64::from(5583992946972634863);
+
+        assert_eq!(eui64.format_into(&mut buf), Ok("4D-7E-54-00-00-97-2E-EF"));
+        assert_eq!(
+            eui64.format_into(&mut [0; 22]),
+            Err(BufferTooSmall { required: 23 })
+        );
+    }
+
+    #[test]
+    fn test_eui48_write_to() {
+        use heapless::String;
+
+        let mut string = String::<32>::new();
+        let eui48 = Eui48::from(85204980412143);
+
+        eui48.write_to(&mut string, EuiFormat::COLON_LOWER).unwrap();
+        string.push_str(" / ").unwrap();
+        eui48.write_to(&mut string, EuiFormat::BARE).unwrap();
+
+        assert_eq!(string, "4d:7e:54:97:2e:ef / 4D7E54972EEF");
+    }
+
+    #[test]
+    fn test_eui64_write_to() {
+        use heapless::String;
+
+        let mut string = String::<23>::new();
+        let eui64 = Eui64::from(5583992946972634863);
+
+        eui64.write_to(&mut string, EuiFormat::default()).unwrap();
+        assert_eq!(string, "4D-7E-54-00-00-97-2E-EF");
+
+        let mut string = String::<8>::new();
+        assert!(eui64.write_to(&mut string, EuiFormat::default()).is_err());
+    }
+
+    #[test]
+    fn test_buffer_too_small_display() {
+        extern crate std;
+        use std::string::ToString;
+
+        assert_eq!(
+            BufferTooSmall { required: 17 }.to_string(),
+            "buffer too small, 17 bytes required"
+        );
+    }
+
+    #[test]
+    fn test_eui48_formatted() {
+        extern crate std;
+        use std::format;
+
+        static FORMATTED: FormattedEui48 = FormattedEui48 {
+            buf: *b"4D-7E-54-97-2E-EF\0\0\0\0\0\0",
+            len: 17,
+        };
+
+        let formatted = Eui48::from(85204980412143).to_canonical();
+
+        assert_eq!(formatted, FORMATTED);
+        assert_eq!(formatted, "4D-7E-54-97-2E-EF");
+        assert_eq!(formatted.len(), 17);
+        assert_eq!(format!("{:>18}", formatted), " 4D-7E-54-97-2E-EF");
+        assert_eq!(format!("{:?}", formatted), "\"4D-7E-54-97-2E-EF\"");
+    }
+
+    #[test]
+    fn test_eui64_formatted() {
+        let formatted = Eui64::from(5583992946972634863).to_canonical();
+        let copy = formatted;
+
+        assert_eq!(formatted.as_ref(), "4D-7E-54-00-00-97-2E-EF");
+        assert_eq!(copy, formatted);
+        assert_eq!(FormattedEui64::clone(&copy).as_str(), &*formatted);
+    }
+}
@@ -0,0 +1,34 @@
+use crate::{Eui, Oui};
+use ::hash32::{Hash, Hasher};
+
+impl<const N: usize> Hash for Eui<N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Hash::hash(&self.0[..], state)
+    }
+}
+
+impl Hash for Oui {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Hash::hash(&self.0[..], state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Eui48, Eui64};
+    use ::hash32::{FnvHasher, Hash, Hasher};
+
+    fn hash<T: Hash>(value: &T) -> u32 {
+        let mut hasher = FnvHasher::default();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn test_hash32() {
+        let octets: [u8; 6] = [0x4d, 0x7e, 0x54, 0x97, 0x2e, 0xef];
+
+        assert_eq!(hash(&Eui48::from(85204980412143)), hash(&octets));
+        assert_ne!(hash(&Eui64::from(1)), hash(&Eui64::from(2)));
+    }
+}
@@ -1,4 +1,4 @@
-//! EUI-48 and EUI-64 no-std implementation using heapless.
+//! EUI-48 and EUI-64 no-std implementation.
 //!
 //! # Example
 //!
@@ -9,59 +9,245 @@
 //! let eui48 = Eui48::from(85204980412143);
 //! let eui64 = Eui64::from(eui48);
 //!     
-//! assert_eq!(eui48.to_string(), "4D-7E-54-97-2E-EF");
-//! assert_eq!(eui64.to_string(), "4D-7E-54-00-00-97-2E-EF");
+//! assert_eq!(eui48.to_canonical(), "4D-7E-54-97-2E-EF");
+//! assert_eq!(eui64.to_canonical(), "4D-7E-54-00-00-97-2E-EF");
 //! ```
 #![no_std]
 
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+mod arith;
+mod binary;
+mod block;
+mod cmp;
 #[cfg(feature = "serde")]
 mod de;
+mod decimal;
+mod derive;
+mod dhcp;
+#[cfg(feature = "eui48")]
+mod eui48;
+mod fdb;
+#[cfg(test)]
+mod fnv;
+mod format;
+#[cfg(feature = "hash32")]
+mod hash32;
+mod list;
+#[cfg(feature = "macaddr")]
+mod macaddr;
+mod mask;
+mod multicast;
+mod neighbor;
+mod octets;
+mod oui;
+mod parse;
+mod pattern;
+mod pool;
+mod prefix;
+mod privacy;
+#[cfg(feature = "rand")]
+mod rand;
+#[cfg(feature = "rand_core")]
+mod random;
+mod range;
+#[cfg(feature = "schemars")]
+mod schema;
 #[cfg(feature = "serde")]
 mod ser;
+#[cfg(feature = "serde")]
+pub mod serde;
+mod set;
+mod slap;
+#[cfg(feature = "smoltcp")]
+mod smoltcp;
+#[cfg(feature = "proptest")]
+pub mod strategy;
+mod stream;
+mod traits;
+#[cfg(feature = "ufmt")]
+mod udisplay;
+#[cfg(feature = "uuid")]
+mod uuid;
+mod wrapper;
+#[cfg(feature = "zeroize")]
+mod zeroize;
+
+pub use block::{BlockError, Eui48Block};
+pub use dhcp::{Duid, DuidError, HARDWARE_TYPE_ETHERNET};
+pub use fdb::FdbTable;
+pub use format::{BufferTooSmall, Case, EuiFormat, FormattedEui48, FormattedEui64, Separator};
+pub use list::{EuiList, ListEntryError};
+pub use mask::{Eui48Mask, MaskError};
+pub use multicast::Ipv4MulticastGroups;
+pub use neighbor::NeighborTable;
+pub use oui::Oui;
+pub use parse::{DetectedFormat, ParseOptions};
+pub use pattern::Eui48Pattern;
+pub use pool::EuiPool;
+pub use prefix::PrefixTable;
+pub use privacy::Prf;
+pub use range::{Eui48Range, Eui64Range};
+pub use set::EuiSet;
+pub use slap::SlapQuadrant;
+pub use stream::EuiParser;
+pub use traits::AnyEui;
+pub use wrapper::{BareHex, Dotted, LowerColon};
 
 use core::convert::TryFrom;
-use core::fmt::{Display, Error, Formatter, LowerHex, UpperHex};
-use heapless::consts::*;
-use heapless::{String, Vec};
+use core::fmt::{Debug, Display, Error, Formatter, LowerHex, UpperHex};
+
+/// I/G bit of the first octet, set for group (multicast) addresses.
+pub(crate) const MULTICAST_BIT: u8 = 0x01;
+/// U/L bit of the first octet, set for locally administered addresses.
+pub(crate) const LOCAL_BIT: u8 = 0x02;
+
+/// Extended unique identifier of `N` octets, layout is guaranteed to be the same as
+/// `[u8; N]`.
+///
+/// Ordered as big-endian integer, default is nil address with every octet zero.
+#[derive(Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Hash)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy::AsBytes,
+        zerocopy::FromBytes,
+        zerocopy::FromZeroes,
+        zerocopy::Unaligned
+    )
+)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    rkyv(compare(PartialEq))
+)]
+#[repr(transparent)]
+pub struct Eui<const N: usize>([u8; N]);
+
+/// EUI-48 identifier, layout is guaranteed to be the same as `[u8; 6]`.
+///
+/// Ordered as big-endian integer, default is nil address `00-00-00-00-00-00`.
+pub type Eui48 = Eui<6>;
+
+/// EUI-64 identifier, layout is guaranteed to be the same as `[u8; 8]`.
+///
+/// Ordered as big-endian integer, default is nil address `00-00-00-00-00-00-00-00`.
+pub type Eui64 = Eui<8>;
+
+/// Archived form of [`Eui48`].
+#[cfg(feature = "rkyv")]
+pub type ArchivedEui48 = ArchivedEui<6>;
+
+/// Archived form of [`Eui64`].
+#[cfg(feature = "rkyv")]
+pub type ArchivedEui64 = ArchivedEui<8>;
+
+impl<const N: usize> Eui<N> {
+    /// Creates eui from its octets.
+    #[inline]
+    pub const fn new(octets: [u8; N]) -> Self {
+        Eui(octets)
+    }
 
-const UPPERCASE_HEX_CHARS: &[u8] = b"0123456789ABCDEF";
+    /// Returns octets of eui.
+    #[inline]
+    pub const fn octets(&self) -> [u8; N] {
+        self.0
+    }
+}
 
-#[derive(Eq, PartialEq, Copy, Clone, Debug, Hash, hash32_derive::Hash32)]
-pub struct Eui48([u8; 6]);
-#[derive(Eq, PartialEq, Copy, Clone, Debug, Hash, hash32_derive::Hash32)]
-pub struct Eui64([u8; 8]);
+#[cfg(feature = "rkyv")]
+impl<const N: usize> Debug for ArchivedEui<N> {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        f.debug_tuple("ArchivedEui").field(&self.0).finish()
+    }
+}
 
-macro_rules! to_hex_string {
-    ($eui: expr, $size: ty) => {{
-        let mut vec = Vec::<u8, $size>::new();
+impl<const N: usize> Default for Eui<N> {
+    fn default() -> Self {
+        Eui([0; N])
+    }
+}
 
-        for (i, &byte) in $eui.0.iter().enumerate() {
-            if i != 0 {
-                vec.push('-' as u8).expect("Vector is not long enough");
-            }
+impl Eui48 {
+    /// Returns dash separated uppercase form.
+    #[inline]
+    pub fn to_canonical(&self) -> FormattedEui48 {
+        self.format_with(EuiFormat::CANONICAL)
+    }
 
-            vec.push(UPPERCASE_HEX_CHARS[(byte >> 4) as usize])
-                .expect("Vector is not long enough");
+    #[deprecated(
+        since = "0.4.0",
+        note = "shadows `ToString::to_string`, use `to_canonical` instead"
+    )]
+    #[inline]
+    pub fn to_string(&self) -> FormattedEui48 {
+        self.to_canonical()
+    }
 
-            vec.push(UPPERCASE_HEX_CHARS[(byte & 0xf) as usize])
-                .expect("Vector is not long enough");
-        }
+    /// Returns dash separated lowercase form.
+    #[inline]
+    pub fn to_string_lower(&self) -> FormattedEui48 {
+        self.format_with(EuiFormat::CANONICAL_LOWER)
+    }
 
-        unsafe { String::from_utf8_unchecked(vec) }
-    }};
-}
+    /// Returns colon separated uppercase form.
+    #[inline]
+    pub fn to_colon_string(&self) -> FormattedEui48 {
+        self.format_with(EuiFormat::COLON)
+    }
 
-impl Eui48 {
+    /// Returns Cisco style dotted lowercase form (`4d7e.5497.2eef`).
     #[inline]
-    pub fn to_string(&self) -> String<U17> {
-        to_hex_string!(self, U17)
+    pub fn to_dotted_string(&self) -> FormattedEui48 {
+        self.format_with(EuiFormat::DOTTED)
+    }
+
+    /// Returns uppercase form without separators.
+    #[inline]
+    pub fn to_hex_string(&self) -> FormattedEui48 {
+        self.format_with(EuiFormat::BARE)
     }
 }
 
 impl Eui64 {
+    /// Returns dash separated uppercase form.
+    #[inline]
+    pub fn to_canonical(&self) -> FormattedEui64 {
+        self.format_with(EuiFormat::CANONICAL)
+    }
+
+    #[deprecated(
+        since = "0.4.0",
+        note = "shadows `ToString::to_string`, use `to_canonical` instead"
+    )]
+    #[inline]
+    pub fn to_string(&self) -> FormattedEui64 {
+        self.to_canonical()
+    }
+
+    /// Returns dash separated lowercase form.
+    #[inline]
+    pub fn to_string_lower(&self) -> FormattedEui64 {
+        self.format_with(EuiFormat::CANONICAL_LOWER)
+    }
+
+    /// Returns colon separated uppercase form.
+    #[inline]
+    pub fn to_colon_string(&self) -> FormattedEui64 {
+        self.format_with(EuiFormat::COLON)
+    }
+
+    /// Returns Cisco style dotted lowercase form (`4d7e.5400.0097.2eef`).
+    #[inline]
+    pub fn to_dotted_string(&self) -> FormattedEui64 {
+        self.format_with(EuiFormat::DOTTED)
+    }
+
+    /// Returns uppercase form without separators.
     #[inline]
-    pub fn to_string(&self) -> String<U23> {
-        to_hex_string!(self, U23)
+    pub fn to_hex_string(&self) -> FormattedEui64 {
+        self.format_with(EuiFormat::BARE)
     }
 }
 
@@ -74,96 +260,284 @@ impl From<u64> for Eui48 {
         let b5: u8 = ((value >> 8) & 0xff) as u8;
         let b6: u8 = (value & 0xff) as u8;
 
-        return Eui48([b1, b2, b3, b4, b5, b6]);
+        Eui([b1, b2, b3, b4, b5, b6])
     }
 }
 
 impl From<u64> for Eui64 {
     fn from(value: u64) -> Self {
-        Eui64(value.to_be_bytes())
+        Eui(value.to_be_bytes())
     }
 }
 
 /// Possible errors while converting string to eui.
+///
+/// `position` is byte index of the offending character in input.
 #[derive(Debug, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum StringToEuiError {
-    InvalidLength { length: usize },
-    InvalidChar { char: char },
-    InvalidSeparatorPlace,
+    InvalidLength {
+        length: usize,
+    },
+    InvalidChar {
+        char: char,
+        position: usize,
+    },
+    /// Separator is misplaced, or missing at `position`.
+    InvalidSeparatorPlace {
+        position: usize,
+    },
     OnlyOneSeparatorTypeExpected,
+    OctetOutOfRange,
+    /// Input ends with half of an octet.
+    OddDigitCount {
+        digits: usize,
+    },
+    /// Input continues after a complete eui.
+    TrailingCharacters {
+        position: usize,
+    },
+    /// Input ends before all octets were read.
+    TooFewOctets {
+        octets: usize,
+        expected: usize,
+    },
+    /// Number of separated groups does not add up to the eui length.
+    GroupCountMismatch {
+        expected: usize,
+        actual: usize,
+    },
+}
+
+impl Display for StringToEuiError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        match self {
+            StringToEuiError::InvalidLength { length } => {
+                write!(f, "invalid eui string length {}", length)
+            }
+            StringToEuiError::InvalidChar { char, position } => {
+                write!(f, "invalid character {:?} at position {}", char, position)
+            }
+            StringToEuiError::InvalidSeparatorPlace { position } => {
+                write!(f, "misplaced or missing separator at position {}", position)
+            }
+            StringToEuiError::OnlyOneSeparatorTypeExpected => {
+                f.write_str("only one type of separator should be used")
+            }
+            StringToEuiError::OctetOutOfRange => f.write_str("octet value must be in range 0-255"),
+            StringToEuiError::OddDigitCount { digits } => {
+                write!(f, "odd number of hexadecimal digits {}", digits)
+            }
+            StringToEuiError::TrailingCharacters { position } => {
+                write!(
+                    f,
+                    "unexpected characters after eui at position {}",
+                    position
+                )
+            }
+            StringToEuiError::TooFewOctets { octets, expected } => {
+                write!(f, "expected {} octets, found {}", expected, octets)
+            }
+            StringToEuiError::GroupCountMismatch { expected, actual } => {
+                write!(f, "expected {} groups, found {}", expected, actual)
+            }
+        }
+    }
+}
+
+impl core::error::Error for StringToEuiError {}
+
+/// Decodes separated or bare hexadecimal octets into `out`, returning number of octets written.
+///
+/// Accepts the same forms as `TryFrom<&str>` for any number of octets up to `out.len()`, so
+/// width of the identifier can be decided after decoding.
+///
+/// # Example
+///
+/// ```rust
+/// let mut out = [0; 8];
+///
+/// assert_eq!(eui::decode_into("4d:7e:54:97:2e:ef", &mut out), Ok(6));
+/// assert_eq!(out[..6], [0x4d, 0x7e, 0x54, 0x97, 0x2e, 0xef]);
+/// ```
+pub fn decode_into(input: &str, out: &mut [u8]) -> Result<usize, StringToEuiError> {
+    if input.is_empty() {
+        return Err(StringToEuiError::InvalidLength { length: 0 });
+    }
+
+    let (digits, _) = decode_ascii(input.as_bytes(), out).map_err(|e| restore_char(input, e))?;
+
+    if digits % 2 != 0 {
+        return Err(StringToEuiError::OddDigitCount { digits });
+    }
+
+    Ok(digits / 2)
 }
 
 pub(crate) fn string_to_eui(input: &str, result: &mut [u8]) -> Result<(), StringToEuiError> {
+    ascii_to_eui(input.as_bytes(), result).map_err(|error| restore_char(input, error))
+}
+
+fn restore_char(input: &str, error: StringToEuiError) -> StringToEuiError {
+    match error {
+        // Parsing stops at the first non-ASCII byte, so it starts a char in input.
+        StringToEuiError::InvalidChar { char, position } if !char.is_ascii() => {
+            StringToEuiError::InvalidChar {
+                char: input
+                    .get(position..)
+                    .and_then(|rest| rest.chars().next())
+                    .unwrap_or(char),
+                position,
+            }
+        }
+        error => error,
+    }
+}
+
+pub(crate) fn hex_digit(byte: u8) -> Option<u8> {
+    match byte {
+        b'A'..=b'F' => Some(byte - b'A' + 10),
+        b'a'..=b'f' => Some(byte - b'a' + 10),
+        b'0'..=b'9' => Some(byte - b'0'),
+        _ => None,
+    }
+}
+
+pub(crate) fn ascii_to_eui(input: &[u8], result: &mut [u8]) -> Result<(), StringToEuiError> {
+    let (digits, separator_type) = decode_ascii(input, result)?;
+
+    if let Some(separator) = separator_type {
+        let group_len = separator_group_len(separator);
+
+        if digits != result.len() * 2 {
+            return Err(StringToEuiError::GroupCountMismatch {
+                expected: result.len() * 2 / group_len,
+                actual: digits / group_len,
+            });
+        }
+    }
+
+    check_digit_count(digits, result.len())
+}
+
+/// Decodes digits into result, returning number of digits and separator found.
+fn decode_ascii(input: &[u8], result: &mut [u8]) -> Result<(usize, Option<u8>), StringToEuiError> {
     let mut separator_type = None;
-    let mut separators = 0;
-
-    for (i, c) in input.chars().enumerate() {
-        let char_byte = c as u8;
-
-        let hex_char_index = match char_byte {
-            b'A'..=b'F' => Some(char_byte - b'A' + 10),
-            b'a'..=b'f' => Some(char_byte - b'a' + 10),
-            b'0'..=b'9' => Some(char_byte - b'0'),
-            _ => None,
-        };
-
-        match hex_char_index {
-            Some(value) => {
-                let current_pos = i - separators;
-                let index = current_pos / 2;
-
-                if index > result.len() - 1 {
-                    return Err(StringToEuiError::InvalidLength {
-                        length: input.len() - separators,
-                    });
+    let mut digits = 0usize;
+    let mut group_digits = 0usize;
+
+    for (position, &byte) in input.iter().enumerate() {
+        if let Some(value) = hex_digit(byte) {
+            // Separated input may contain separator after every second digit
+            // or, for Cisco dotted form, after every fourth digit.
+            if let Some(separator) = separator_type {
+                if group_digits == separator_group_len(separator) {
+                    return Err(StringToEuiError::InvalidSeparatorPlace { position });
                 }
+            }
 
-                if current_pos % 2 == 0 {
-                    result[index] = (value as u8) << 4 & 0xF0
-                } else {
-                    result[index] |= value as u8 & 0xF
-                }
+            let octet = match result.get_mut(digits / 2) {
+                Some(octet) => octet,
+                None => return Err(StringToEuiError::TrailingCharacters { position }),
+            };
+
+            if digits % 2 == 0 {
+                *octet = value << 4;
+            } else {
+                *octet |= value;
             }
-            None if c == ':' || c == '-' => {
-                // String may contain separator after every second character.
-                if i == 0 || i == input.len() || (i + 1) % 3 != 0 {
-                    return Err(StringToEuiError::InvalidSeparatorPlace);
-                }
 
-                match separator_type {
-                    Some(t) => {
-                        if t != c {
-                            return Err(StringToEuiError::OnlyOneSeparatorTypeExpected);
-                        }
-                    }
-                    None => separator_type = Some(c),
-                }
+            digits += 1;
+            group_digits += 1;
+        } else if matches!(byte, b':' | b'-' | b'.' | b' ') {
+            let group_len = separator_group_len(separator_type.unwrap_or(byte));
 
-                separators += 1;
+            if group_digits != group_len {
+                return Err(StringToEuiError::InvalidSeparatorPlace { position });
             }
-            None => {
-                return Err(StringToEuiError::InvalidChar { char: c });
+
+            if digits == result.len() * 2 {
+                return Err(StringToEuiError::GroupCountMismatch {
+                    expected: digits / group_len,
+                    actual: input.iter().filter(|&&b| b == byte).count() + 1,
+                });
+            }
+
+            match separator_type {
+                Some(t) if t != byte => {
+                    return Err(StringToEuiError::OnlyOneSeparatorTypeExpected);
+                }
+                _ => separator_type = Some(byte),
             }
+
+            group_digits = 0;
+        } else {
+            // Non-ASCII bytes are reported as Latin-1 chars, `string_to_eui` restores
+            // the original char.
+            return Err(StringToEuiError::InvalidChar {
+                char: byte as char,
+                position,
+            });
         }
     }
 
-    Ok(())
+    if let Some(separator) = separator_type {
+        if group_digits != separator_group_len(separator) {
+            return Err(StringToEuiError::InvalidSeparatorPlace {
+                position: input.len(),
+            });
+        }
+    }
+
+    Ok((digits, separator_type))
+}
+
+/// Checks that collected hexadecimal digits fill all octets of result.
+pub(crate) fn check_digit_count(digits: usize, octets: usize) -> Result<(), StringToEuiError> {
+    if digits % 2 != 0 {
+        Err(StringToEuiError::OddDigitCount { digits })
+    } else if digits < octets * 2 {
+        Err(StringToEuiError::TooFewOctets {
+            octets: digits / 2,
+            expected: octets,
+        })
+    } else {
+        Ok(())
+    }
+}
+
+fn separator_group_len(separator: u8) -> usize {
+    if separator == b'.' {
+        4
+    } else {
+        2
+    }
+}
+
+pub(crate) fn check_string_length(
+    length: usize,
+    allowed: &[usize],
+) -> Result<(), StringToEuiError> {
+    if allowed.contains(&length) {
+        Ok(())
+    } else {
+        Err(StringToEuiError::InvalidLength { length })
+    }
 }
 
+pub(crate) const EUI48_STRING_LENGTHS: [usize; 3] = [12, 14, 17];
+pub(crate) const EUI64_STRING_LENGTHS: [usize; 3] = [16, 19, 23];
+
 impl TryFrom<&str> for Eui48 {
     type Error = StringToEuiError;
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
-        if value.len() != 12 && value.len() != 17 {
-            return Err(StringToEuiError::InvalidLength {
-                length: value.len(),
-            });
-        }
+        check_string_length(value.len(), &EUI48_STRING_LENGTHS)?;
 
         let mut result = [0; 6];
         string_to_eui(value, &mut result[..])?;
 
-        Ok(Eui48(result))
+        Ok(Eui(result))
     }
 }
 
@@ -171,16 +545,12 @@ impl TryFrom<&str> for Eui64 {
     type Error = StringToEuiError;
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
-        if value.len() != 16 && value.len() != 23 {
-            return Err(StringToEuiError::InvalidLength {
-                length: value.len(),
-            });
-        }
+        check_string_length(value.len(), &EUI64_STRING_LENGTHS)?;
 
         let mut result = [0; 8];
         string_to_eui(value, &mut result[..])?;
 
-        Ok(Eui64(result))
+        Ok(Eui(result))
     }
 }
 
@@ -188,15 +558,10 @@ impl From<Eui48> for Eui64 {
     fn from(eui48: Eui48) -> Self {
         let mut data = [0u8; 8];
 
-        for i in 0..3 {
-            data[i] = eui48.0[i]
-        }
+        data[..3].copy_from_slice(&eui48.0[..3]);
+        data[5..].copy_from_slice(&eui48.0[3..]);
 
-        for i in 5..8 {
-            data[i] = eui48.0[i - 2]
-        }
-
-        Eui64(data)
+        Eui(data)
     }
 }
 
@@ -209,7 +574,7 @@ impl From<Eui48> for u64 {
             + ((data[2] as u64) << 24)
             + ((data[3] as u64) << 16)
             + ((data[4] as u64) << 8)
-            + ((data[5] as u64) << 0)
+            + (data[5] as u64)
     }
 }
 
@@ -219,43 +584,100 @@ impl From<Eui64> for u64 {
     }
 }
 
+/// Alternate flag (`{:#}`) prints lowercase colon separated form.
 impl Display for Eui48 {
     fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
-        write!(f, "{}", self.to_string())
+        if f.alternate() {
+            f.pad(&self.format_with(EuiFormat::COLON_LOWER))
+        } else {
+            f.pad(&self.to_canonical())
+        }
     }
 }
 
+/// Alternate flag (`{:#}`) prints lowercase colon separated form.
 impl Display for Eui64 {
     fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
-        write!(f, "{}", self.to_string())
+        if f.alternate() {
+            f.pad(&self.format_with(EuiFormat::COLON_LOWER))
+        } else {
+            f.pad(&self.to_canonical())
+        }
+    }
+}
+
+impl Debug for Eui48 {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        write!(f, "Eui48({})", self.to_canonical())
+    }
+}
+
+impl Debug for Eui64 {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        write!(f, "Eui64({})", self.to_canonical())
     }
 }
 
+/// Prints every digit without separators, alternate flag (`{:#X}`) prints dash separated uppercase form.
 impl UpperHex for Eui48 {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
-        write!(f, "{:X}", u64::from(*self))
+        if f.alternate() {
+            f.pad(&self.format_with(EuiFormat::CANONICAL))
+        } else {
+            f.pad_integral(true, "", &self.format_with(EuiFormat::BARE))
+        }
     }
 }
 
+/// Prints every digit without separators, alternate flag (`{:#x}`) prints colon separated lowercase form.
 impl LowerHex for Eui48 {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
-        write!(f, "{:x}", u64::from(*self))
+        if f.alternate() {
+            f.pad(&self.format_with(EuiFormat::COLON_LOWER))
+        } else {
+            f.pad_integral(true, "", &self.format_with(EuiFormat::BARE_LOWER))
+        }
     }
 }
 
+/// Prints every digit without separators, alternate flag (`{:#X}`) prints dash separated uppercase form.
 impl UpperHex for Eui64 {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
-        write!(f, "{:X}", u64::from(*self))
+        if f.alternate() {
+            f.pad(&self.format_with(EuiFormat::CANONICAL))
+        } else {
+            f.pad_integral(true, "", &self.format_with(EuiFormat::BARE))
+        }
     }
 }
 
+/// Prints every digit without separators, alternate flag (`{:#x}`) prints colon separated lowercase form.
 impl LowerHex for Eui64 {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
-        write!(f, "{:x}", u64::from(*self))
+        if f.alternate() {
+            f.pad(&self.format_with(EuiFormat::COLON_LOWER))
+        } else {
+            f.pad_integral(true, "", &self.format_with(EuiFormat::BARE_LOWER))
+        }
     }
 }
 
 #[test]
+fn test_eui48_to_canonical() {
+    let eui48 = Eui48::from(85204980412143);
+
+    assert_eq!(eui48.to_canonical(), "4D-7E-54-97-2E-EF")
+}
+
+#[test]
+fn test_eui64_to_canonical() {
+    let eui64 = Eui64::from(5583992946972634863);
+
+    assert_eq!(eui64.to_canonical(), "4D-7E-54-00-00-97-2E-EF")
+}
+
+#[test]
+#[allow(deprecated)]
 fn test_eui48_to_string() {
     let eui48 = Eui48::from(85204980412143);
 
@@ -263,18 +685,79 @@ fn test_eui48_to_string() {
 }
 
 #[test]
+#[allow(deprecated)]
 fn test_eui64_to_string() {
     let eui64 = Eui64::from(5583992946972634863);
 
     assert_eq!(eui64.to_string(), "4D-7E-54-00-00-97-2E-EF")
 }
 
+#[test]
+fn test_eui48_to_string_lower() {
+    let eui48 = Eui48::from(85204980412143);
+
+    assert_eq!(eui48.to_string_lower(), "4d-7e-54-97-2e-ef")
+}
+
+#[test]
+fn test_eui64_to_string_lower() {
+    let eui64 = Eui64::from(5583992946972634863);
+
+    assert_eq!(eui64.to_string_lower(), "4d-7e-54-00-00-97-2e-ef")
+}
+
+#[test]
+fn test_eui48_to_colon_string() {
+    let eui48 = Eui48::from(85204980412143);
+
+    assert_eq!(eui48.to_colon_string(), "4D:7E:54:97:2E:EF");
+    assert_eq!(Eui48::try_from(&eui48.to_colon_string()[..]), Ok(eui48));
+}
+
+#[test]
+fn test_eui64_to_colon_string() {
+    let eui64 = Eui64::from(5583992946972634863);
+
+    assert_eq!(eui64.to_colon_string(), "4D:7E:54:00:00:97:2E:EF");
+    assert_eq!(Eui64::try_from(&eui64.to_colon_string()[..]), Ok(eui64));
+}
+
+#[test]
+fn test_eui48_to_dotted_string() {
+    let eui48 = Eui48::from(85204980412143);
+
+    assert_eq!(eui48.to_dotted_string(), "4d7e.5497.2eef");
+}
+
+#[test]
+fn test_eui64_to_dotted_string() {
+    let eui64 = Eui64::from(5583992946972634863);
+
+    assert_eq!(eui64.to_dotted_string(), "4d7e.5400.0097.2eef");
+}
+
+#[test]
+fn test_eui48_to_hex_string() {
+    let eui48 = Eui48::from(85204980412143);
+
+    assert_eq!(eui48.to_hex_string(), "4D7E54972EEF");
+    assert_eq!(Eui48::try_from(&eui48.to_hex_string()[..]), Ok(eui48));
+}
+
+#[test]
+fn test_eui64_to_hex_string() {
+    let eui64 = Eui64::from(5583992946972634863);
+
+    assert_eq!(eui64.to_hex_string(), "4D7E540000972EEF");
+    assert_eq!(Eui64::try_from(&eui64.to_hex_string()[..]), Ok(eui64));
+}
+
 #[test]
 fn test_eui48_to_eui64() {
     let eui48 = Eui48::from(85204980412143);
     let eui64 = Eui64::from(eui48);
 
-    assert_eq!(eui64.to_string(), "4D-7E-54-00-00-97-2E-EF")
+    assert_eq!(eui64.to_canonical(), "4D-7E-54-00-00-97-2E-EF")
 }
 
 #[test]
@@ -295,7 +778,7 @@ fn test_hash_eui48() {
 
     let eui48 = Eui48::from(85204980412143);
 
-    let mut fnv_index_map: FnvIndexMap<Eui48, u8, U1> = FnvIndexMap::new();
+    let mut fnv_index_map: FnvIndexMap<Eui48, u8, 2> = FnvIndexMap::new();
     fnv_index_map.insert(eui48, 1).unwrap();
 
     assert_eq!(1, *fnv_index_map.get(&eui48).unwrap())
@@ -307,7 +790,7 @@ fn test_hash_eui64() {
 
     let eui64 = Eui64::from(5583992946972634863);
 
-    let mut fnv_index_map: FnvIndexMap<Eui64, u8, U1> = FnvIndexMap::new();
+    let mut fnv_index_map: FnvIndexMap<Eui64, u8, 2> = FnvIndexMap::new();
     fnv_index_map.insert(eui64, 1).unwrap();
 
     assert_eq!(1, *fnv_index_map.get(&eui64).unwrap())
@@ -333,6 +816,77 @@ fn test_display_eui64() {
     assert_eq!(format!("{}", eui64), "4D-7E-54-00-00-97-2E-EF");
 }
 
+#[test]
+fn test_display_eui48_with_padding() {
+    extern crate std;
+    use std::format;
+
+    let eui48 = Eui48::from(85204980412143);
+
+    assert_eq!(format!("{:>20}", eui48), "   4D-7E-54-97-2E-EF");
+    assert_eq!(format!("{:*^21}", eui48), "**4D-7E-54-97-2E-EF**");
+    assert_eq!(
+        format!("{:<width$}|", eui48, width = 18),
+        "4D-7E-54-97-2E-EF |"
+    );
+}
+
+#[test]
+fn test_display_eui64_with_padding() {
+    extern crate std;
+    use std::format;
+
+    let eui64 = Eui64::from(5583992946972634863);
+
+    assert_eq!(format!("{:>25}", eui64), "  4D-7E-54-00-00-97-2E-EF");
+    assert_eq!(format!("{:-<24}", eui64), "4D-7E-54-00-00-97-2E-EF-");
+}
+
+#[test]
+fn test_display_alternate_eui48() {
+    extern crate std;
+    use std::format;
+
+    let eui48 = Eui48::from(85204980412143);
+
+    assert_eq!(format!("{:#}", eui48), "4d:7e:54:97:2e:ef");
+    assert_eq!(format!("{:>#19}", eui48), "  4d:7e:54:97:2e:ef");
+}
+
+#[test]
+fn test_display_alternate_eui64() {
+    extern crate std;
+    use std::format;
+
+    let eui64 = Eui64::from(5583992946972634863);
+
+    assert_eq!(format!("{:#}", eui64), "4d:7e:54:00:00:97:2e:ef");
+}
+
+#[test]
+fn test_debug_eui48() {
+    extern crate std;
+    use std::format;
+
+    let eui48 = Eui48::from(85204980412143);
+
+    assert_eq!(format!("{:?}", eui48), "Eui48(4D-7E-54-97-2E-EF)");
+    assert_eq!(
+        format!("{:?}", Some(eui48)),
+        "Some(Eui48(4D-7E-54-97-2E-EF))"
+    );
+}
+
+#[test]
+fn test_debug_eui64() {
+    extern crate std;
+    use std::format;
+
+    let eui64 = Eui64::from(5583992946972634863);
+
+    assert_eq!(format!("{:?}", eui64), "Eui64(4D-7E-54-00-00-97-2E-EF)");
+}
+
 #[test]
 fn test_format_upper_hex_eui48() {
     extern crate std;
@@ -373,6 +927,53 @@ fn test_format_lower_hex_eui64() {
     assert_eq!(format!("{:x}", eui64), "4d7e540000972eef");
 }
 
+#[test]
+fn test_format_hex_leading_zeros_eui48() {
+    extern crate std;
+    use std::format;
+
+    let eui48 = Eui48::from(0x00005E0053AF);
+
+    assert_eq!(format!("{:X}", eui48), "00005E0053AF");
+    assert_eq!(format!("{:x}", eui48), "00005e0053af");
+    assert_eq!(format!("{:14X}", eui48), "  00005E0053AF");
+    assert_eq!(format!("{:014x}", eui48), "0000005e0053af");
+}
+
+#[test]
+fn test_format_hex_leading_zeros_eui64() {
+    extern crate std;
+    use std::format;
+
+    let eui64 = Eui64::from(0x00005EFFFE0053AF);
+
+    assert_eq!(format!("{:X}", eui64), "00005EFFFE0053AF");
+    assert_eq!(format!("{:<18x}|", eui64), "00005efffe0053af  |");
+}
+
+#[test]
+fn test_format_alternate_hex_eui48() {
+    extern crate std;
+    use std::format;
+
+    let eui48 = Eui48::from(85204980412143);
+
+    assert_eq!(format!("{:#X}", eui48), "4D-7E-54-97-2E-EF");
+    assert_eq!(format!("{:#x}", eui48), "4d:7e:54:97:2e:ef");
+    assert_eq!(format!("{:>#18X}", eui48), " 4D-7E-54-97-2E-EF");
+}
+
+#[test]
+fn test_format_alternate_hex_eui64() {
+    extern crate std;
+    use std::format;
+
+    let eui64 = Eui64::from(5583992946972634863);
+
+    assert_eq!(format!("{:#X}", eui64), "4D-7E-54-00-00-97-2E-EF");
+    assert_eq!(format!("{:#x}", eui64), "4d:7e:54:00:00:97:2e:ef");
+}
+
 #[test]
 fn test_eui48_try_from_string() {
     let eui48 = Eui48::try_from("4D7E54972EEF").unwrap();
@@ -405,6 +1006,59 @@ fn test_eui64_try_from_string_with_separator() {
     assert_eq!(u64::from(eui64_2), 5583992946972634863);
 }
 
+#[test]
+fn test_eui48_try_from_space_separated_string() {
+    let eui48 = Eui48::try_from("4D 7E 54 97 2E EF").unwrap();
+
+    assert_eq!(u64::from(eui48), 85204980412143);
+
+    assert_eq!(
+        Eui48::try_from("4D 7E 54:97 2E EF").err().unwrap(),
+        StringToEuiError::OnlyOneSeparatorTypeExpected
+    );
+}
+
+#[test]
+fn test_eui64_try_from_space_separated_string() {
+    let eui64 = Eui64::try_from("4d 7e 54 00 00 97 2e ef").unwrap();
+
+    assert_eq!(u64::from(eui64), 5583992946972634863);
+}
+
+#[test]
+fn test_eui48_try_from_dotted_string() {
+    let eui48_1 = Eui48::try_from("4d7e.5497.2eef").unwrap();
+    let eui48_2 = Eui48::try_from("4D7E.5497.2EEF").unwrap();
+
+    assert_eq!(u64::from(eui48_1), 85204980412143);
+    assert_eq!(u64::from(eui48_2), 85204980412143);
+}
+
+#[test]
+fn test_eui64_try_from_dotted_string() {
+    let eui64 = Eui64::try_from("4d7e.5400.0097.2eef").unwrap();
+
+    assert_eq!(u64::from(eui64), 5583992946972634863);
+}
+
+#[test]
+fn test_eui48_try_from_invalid_dotted_string() {
+    assert_eq!(
+        Eui48::try_from("4d7e5.497.2eef").err().unwrap(),
+        StringToEuiError::InvalidSeparatorPlace { position: 5 }
+    );
+
+    assert_eq!(
+        Eui48::try_from("4d.7e5497.2eef").err().unwrap(),
+        StringToEuiError::InvalidSeparatorPlace { position: 2 }
+    );
+
+    assert_eq!(
+        Eui48::try_from("4d7e.54:97:2ef").err().unwrap(),
+        StringToEuiError::InvalidSeparatorPlace { position: 7 }
+    );
+}
+
 #[test]
 fn test_eui48_try_from_invalid_length() {
     assert_eq!(
@@ -424,7 +1078,7 @@ fn test_eui48_try_from_invalid_length() {
 
     assert_eq!(
         Eui48::try_from("4d7e54972eefef4da").err().unwrap(),
-        StringToEuiError::InvalidLength { length: 17 }
+        StringToEuiError::TrailingCharacters { position: 12 }
     );
 }
 
@@ -446,11 +1100,124 @@ fn test_eui64_try_from_invalid_length() {
     );
 }
 
+#[test]
+fn test_eui48_try_from_too_few_octets() {
+    assert_eq!(
+        Eui48::try_from("4D-7E-54-97-2E").err().unwrap(),
+        StringToEuiError::GroupCountMismatch {
+            expected: 6,
+            actual: 5
+        }
+    );
+    assert_eq!(
+        Eui48::try_from("4D-7E-54-97-2E-").err().unwrap(),
+        StringToEuiError::InvalidLength { length: 15 }
+    );
+}
+
+#[test]
+fn test_decode_into() {
+    let mut out = [0; 8];
+
+    assert_eq!(decode_into("4D-7E-54-97-2E-EF", &mut out), Ok(6));
+    assert_eq!(out[..6], [0x4d, 0x7e, 0x54, 0x97, 0x2e, 0xef]);
+    assert_eq!(decode_into("4d7e.5400.0097.2eef", &mut out), Ok(8));
+    assert_eq!(out, 0x4d7e540000972eefu64.to_be_bytes());
+    assert_eq!(decode_into("4d7e", &mut out), Ok(2));
+}
+
+#[test]
+fn test_decode_into_invalid() {
+    let mut out = [0; 6];
+
+    assert_eq!(
+        decode_into("", &mut out),
+        Err(StringToEuiError::InvalidLength { length: 0 })
+    );
+    assert_eq!(
+        decode_into("4d7e5", &mut out),
+        Err(StringToEuiError::OddDigitCount { digits: 5 })
+    );
+    assert_eq!(
+        decode_into("4d:7e:54:00:00:97:2e:ef", &mut out),
+        Err(StringToEuiError::GroupCountMismatch {
+            expected: 6,
+            actual: 8
+        })
+    );
+    assert_eq!(
+        decode_into("4d:7e:", &mut out),
+        Err(StringToEuiError::InvalidSeparatorPlace { position: 6 })
+    );
+}
+
+#[test]
+fn test_eui48_parse_ascii_adversarial_input() {
+    for input in [
+        &b""[..],
+        b"-",
+        b"\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF",
+        b"\xC3\xA9\xC3\xA9\xC3\xA9\xC3\xA9\xC3\xA9\xC3\xA9",
+        b"::::::::::::::::",
+        b"4d7e54972eef4d7e5",
+        b"4d7e54972eeff",
+    ]
+    .iter()
+    {
+        assert!(Eui48::parse_ascii(input).is_err());
+    }
+}
+
+#[test]
+fn test_string_to_eui_error_display() {
+    extern crate std;
+    use std::format;
+
+    assert_eq!(
+        format!("{}", Eui48::try_from("ad7e54972eja").err().unwrap()),
+        "invalid character 'j' at position 10"
+    );
+    assert_eq!(
+        format!("{}", Eui48::try_from("4d7e54972e").err().unwrap()),
+        "invalid eui string length 10"
+    );
+    assert_eq!(
+        format!(
+            "{}",
+            StringToEuiError::InvalidSeparatorPlace { position: 2 }
+        ),
+        "misplaced or missing separator at position 2"
+    );
+}
+
+#[test]
+fn test_string_to_eui_error_is_error() {
+    fn source(error: &dyn core::error::Error) -> Option<&dyn core::error::Error> {
+        error.source()
+    }
+
+    assert!(source(&StringToEuiError::OctetOutOfRange).is_none());
+}
+
 #[test]
 fn test_eui48_try_from_invalid_character() {
     assert_eq!(
         Eui48::try_from("ad7e54972eja").err().unwrap(),
-        StringToEuiError::InvalidChar { char: 'j' }
+        StringToEuiError::InvalidChar {
+            char: 'j',
+            position: 10
+        }
+    );
+}
+
+#[test]
+fn test_eui48_try_from_non_ascii_character() {
+    assert_eq!(
+        Eui48::try_from("4d7e54972eé").err().unwrap(),
+        StringToEuiError::InvalidChar {
+            char: 'é',
+            position: 10
+        }
     );
 }
 
@@ -458,7 +1225,10 @@ fn test_eui48_try_from_invalid_character() {
 fn test_eui64_try_from_invalid_character() {
     assert_eq!(
         Eui64::try_from("ad7e54972ea721sa").err().unwrap(),
-        StringToEuiError::InvalidChar { char: 's' }
+        StringToEuiError::InvalidChar {
+            char: 's',
+            position: 14
+        }
     );
 }
 
@@ -466,17 +1236,17 @@ fn test_eui64_try_from_invalid_character() {
 fn test_eui48_try_from_invalid_separator_position() {
     assert_eq!(
         Eui48::try_from(":4d7e:54:97:2e:ef").err().unwrap(),
-        StringToEuiError::InvalidSeparatorPlace
+        StringToEuiError::InvalidSeparatorPlace { position: 0 }
     );
 
     assert_eq!(
         Eui48::try_from("4d:7e:54:97:2eef:").err().unwrap(),
-        StringToEuiError::InvalidSeparatorPlace
+        StringToEuiError::InvalidSeparatorPlace { position: 14 }
     );
 
     assert_eq!(
         Eui48::try_from("4d::7e54:97:2e:ef").err().unwrap(),
-        StringToEuiError::InvalidSeparatorPlace
+        StringToEuiError::InvalidSeparatorPlace { position: 3 }
     );
 }
 
@@ -484,17 +1254,17 @@ fn test_eui48_try_from_invalid_separator_position() {
 fn test_eui64_try_from_invalid_separator_position() {
     assert_eq!(
         Eui64::try_from(":4d7e:54:00:00:97:2e:ef").err().unwrap(),
-        StringToEuiError::InvalidSeparatorPlace
+        StringToEuiError::InvalidSeparatorPlace { position: 0 }
     );
 
     assert_eq!(
         Eui64::try_from("4d:7e:54:00:00:97:2eef:").err().unwrap(),
-        StringToEuiError::InvalidSeparatorPlace
+        StringToEuiError::InvalidSeparatorPlace { position: 20 }
     );
 
     assert_eq!(
         Eui64::try_from("4d::7e54:00:00:97:2e:ef").err().unwrap(),
-        StringToEuiError::InvalidSeparatorPlace
+        StringToEuiError::InvalidSeparatorPlace { position: 3 }
     );
 }
 
@@ -513,3 +1283,110 @@ fn test_eui64_try_from_string_different_separators() {
         StringToEuiError::OnlyOneSeparatorTypeExpected
     );
 }
+
+#[test]
+#[cfg(feature = "zerocopy")]
+fn test_zerocopy_ethernet_header() {
+    use zerocopy::{AsBytes, FromBytes, FromZeroes, Unaligned};
+
+    #[derive(AsBytes, FromBytes, FromZeroes, Unaligned)]
+    #[repr(C)]
+    struct EthernetHeader {
+        destination: Eui48,
+        source: Eui48,
+        ether_type: [u8; 2],
+    }
+
+    let frame = [
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x4d, 0x7e, 0x54, 0x97, 0x2e, 0xef, 0x08, 0x06,
+    ];
+    let header = EthernetHeader::ref_from(&frame[..]).unwrap();
+
+    assert_eq!(header.destination, Eui48::from(0xffff_ffff_ffff));
+    assert_eq!(header.source, Eui48::from(85204980412143));
+    assert_eq!(header.as_bytes(), &frame[..]);
+    assert_eq!(
+        Eui64::read_from(&[0x4d, 0x7e, 0x54, 0, 0, 0x97, 0x2e, 0xef][..]),
+        Some(Eui64::from(5583992946972634863))
+    );
+}
+
+#[test]
+#[cfg(feature = "rkyv")]
+fn test_rkyv_archive() {
+    use rkyv::rancor::Error;
+
+    let inventory = [Eui48::from(85204980412143), Eui48::from(1)];
+    let bytes = rkyv::to_bytes::<Error>(&inventory).unwrap();
+    let archived = rkyv::access::<[ArchivedEui48; 2], Error>(&bytes).unwrap();
+
+    assert_eq!(archived[0], inventory[0]);
+    assert_eq!(
+        rkyv::deserialize::<[Eui48; 2], Error>(archived).unwrap(),
+        inventory
+    );
+}
+
+#[test]
+fn test_ordering() {
+    let eui48 = Eui48::from(85204980412143);
+
+    assert!(eui48 < Eui48::from(85204980412144));
+    assert!(Eui48::from(0x0100_0000_0000) > Eui48::from(0x00FF_FFFF_FFFF));
+    assert_eq!(eui48.max(Eui48::from(1)), eui48);
+    assert!(Eui64::from(0x0100_0000_0000_0000) > Eui64::from(0xFF));
+
+    let sorted = [Eui48::from(1), Eui48::from(0x100), eui48];
+    assert_eq!(sorted.binary_search(&Eui48::from(0x100)), Ok(1));
+}
+
+#[test]
+fn test_default() {
+    #[derive(Default)]
+    struct Config {
+        mac: Eui48,
+        eui64: Eui64,
+    }
+
+    let config = Config::default();
+
+    assert_eq!(config.mac, Eui48::from(0));
+    assert_eq!(config.eui64, Eui64::from(0));
+    assert_eq!(Oui::default(), Oui::new([0; 3]));
+}
+
+#[test]
+fn test_hash_map_key() {
+    extern crate std;
+    use std::collections::HashMap;
+
+    let mut names = HashMap::new();
+    names.insert(Eui48::from(85204980412143), "eth0");
+    names.insert(Eui48::from(1), "eth1");
+
+    assert_eq!(names.get(&Eui48::from(85204980412143)), Some(&"eth0"));
+
+    let mut interfaces = HashMap::new();
+    interfaces.insert(Eui64::from(Eui48::from(1)), Oui::new([0, 0, 0]));
+
+    assert!(interfaces.contains_key(&Eui64::from(Eui48::from(1))));
+}
+
+#[test]
+fn test_generic_width() {
+    let mut octets = [0u8; 20];
+    octets[19] = 0xEF;
+
+    let mut ipoib = Eui::new(octets);
+    ipoib[0] = 0x02;
+
+    assert!(ipoib.get_bit(1));
+    assert_eq!(ipoib.get(19), Some(0xEF));
+    assert_eq!(ipoib.into_iter().filter(|&octet| octet != 0).count(), 2);
+    assert!(ipoib.eq_ignore_local_bit(&Eui::new(octets)));
+    assert!(Eui::<20>::default() < ipoib);
+    assert_eq!(
+        Eui48::new([0x4d, 0x7e, 0x54, 0x97, 0x2e, 0xef]).octets(),
+        [0x4d, 0x7e, 0x54, 0x97, 0x2e, 0xef]
+    );
+}
@@ -0,0 +1,179 @@
+use crate::{Eui48, Eui64, StringToEuiError};
+use core::convert::TryFrom;
+use core::fmt::{Display, Error, Formatter};
+use core::marker::PhantomData;
+
+/// Error of a single entry returned by `parse_list`.
+#[derive(Debug, PartialEq, Eq)]
+pub struct ListEntryError {
+    /// Byte offset of the entry start within the whole input.
+    pub position: usize,
+    /// Error with positions relative to the entry start.
+    pub error: StringToEuiError,
+}
+
+impl Display for ListEntryError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        write!(f, "entry at position {}: {}", self.position, self.error)
+    }
+}
+
+impl core::error::Error for ListEntryError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// Iterator over euis in delimited list, created by `parse_list`.
+///
+/// Entries are trimmed and empty entries are skipped. Whitespace delimiter matches any
+/// whitespace, so `' '` splits on spaces, tabs and newlines.
+#[derive(Clone, Debug)]
+pub struct EuiList<'a, T> {
+    input: &'a str,
+    position: usize,
+    delimiter: char,
+    eui: PhantomData<T>,
+}
+
+impl<'a, T> EuiList<'a, T> {
+    fn new(input: &'a str, delimiter: char) -> Self {
+        EuiList {
+            input,
+            position: 0,
+            delimiter,
+            eui: PhantomData,
+        }
+    }
+
+    fn is_delimiter(&self, c: char) -> bool {
+        if self.delimiter.is_whitespace() {
+            c.is_whitespace()
+        } else {
+            c == self.delimiter
+        }
+    }
+}
+
+impl<'a, T> Iterator for EuiList<'a, T>
+where
+    T: TryFrom<&'a str, Error = StringToEuiError>,
+{
+    type Item = Result<T, ListEntryError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.input.is_empty() {
+            let (entry, rest) = match self
+                .input
+                .char_indices()
+                .find(|(_, c)| self.is_delimiter(*c))
+            {
+                Some((i, c)) => (&self.input[..i], &self.input[i + c.len_utf8()..]),
+                None => (self.input, ""),
+            };
+
+            let start = self.position + entry.len() - entry.trim_start().len();
+
+            self.position += self.input.len() - rest.len();
+            self.input = rest;
+
+            let entry = entry.trim();
+
+            if entry.is_empty() {
+                continue;
+            }
+
+            return Some(T::try_from(entry).map_err(|error| ListEntryError {
+                position: start,
+                error,
+            }));
+        }
+
+        None
+    }
+}
+
+impl Eui48 {
+    /// Parses list of euis separated by delimiter without allocation.
+    pub fn parse_list(input: &str, delimiter: char) -> EuiList<'_, Eui48> {
+        EuiList::new(input, delimiter)
+    }
+}
+
+impl Eui64 {
+    /// Parses list of euis separated by delimiter without allocation.
+    pub fn parse_list(input: &str, delimiter: char) -> EuiList<'_, Eui64> {
+        EuiList::new(input, delimiter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Eui48, Eui64, ListEntryError, StringToEuiError};
+
+    extern crate std;
+    use std::string::ToString;
+
+    #[test]
+    fn test_list_entry_error_display() {
+        let error = ListEntryError {
+            position: 19,
+            error: StringToEuiError::InvalidLength { length: 3 },
+        };
+
+        assert_eq!(
+            error.to_string(),
+            "entry at position 19: invalid eui string length 3"
+        );
+        assert!(core::error::Error::source(&error).is_some());
+    }
+
+    #[test]
+    fn test_eui48_parse_list() {
+        let mut list = Eui48::parse_list("4D-7E-54-97-2E-EF, 4d:7e:54:97:2e:f0,,", ',');
+
+        assert_eq!(list.next(), Some(Ok(Eui48::from(85204980412143))));
+        assert_eq!(list.next(), Some(Ok(Eui48::from(85204980412144))));
+        assert_eq!(list.next(), None);
+    }
+
+    #[test]
+    fn test_eui48_parse_list_whitespace() {
+        let list = Eui48::parse_list("4d7e54972eef\n\t4d7e54972ef0  ", ' ');
+
+        assert_eq!(list.filter(Result::is_ok).count(), 2);
+    }
+
+    #[test]
+    fn test_eui64_parse_list() {
+        let mut list = Eui64::parse_list("4D-7E-54-00-00-97-2E-EF;", ';');
+
+        assert_eq!(list.next(), Some(Ok(Eui64::from(5583992946972634863))));
+        assert_eq!(list.next(), None);
+    }
+
+    #[test]
+    fn test_eui48_parse_list_error_position() {
+        let mut list = Eui48::parse_list("4d7e54972eef;  4d7e54972eeg;4d7e", ';');
+
+        assert!(list.next().unwrap().is_ok());
+        assert_eq!(
+            list.next(),
+            Some(Err(ListEntryError {
+                position: 15,
+                error: StringToEuiError::InvalidChar {
+                    char: 'g',
+                    position: 11
+                },
+            }))
+        );
+        assert_eq!(
+            list.next(),
+            Some(Err(ListEntryError {
+                position: 28,
+                error: StringToEuiError::InvalidLength { length: 4 },
+            }))
+        );
+        assert_eq!(list.next(), None);
+    }
+}
@@ -0,0 +1,70 @@
+use crate::{Eui, Eui48, Eui64};
+use ::macaddr::{MacAddr, MacAddr6, MacAddr8};
+
+impl From<MacAddr6> for Eui48 {
+    fn from(address: MacAddr6) -> Self {
+        let mut octets = [0; 6];
+        octets.copy_from_slice(address.as_bytes());
+
+        Eui(octets)
+    }
+}
+
+impl From<Eui48> for MacAddr6 {
+    fn from(eui48: Eui48) -> Self {
+        MacAddr6::from(eui48.0)
+    }
+}
+
+impl From<MacAddr8> for Eui64 {
+    fn from(address: MacAddr8) -> Self {
+        let mut octets = [0; 8];
+        octets.copy_from_slice(address.as_bytes());
+
+        Eui(octets)
+    }
+}
+
+impl From<Eui64> for MacAddr8 {
+    fn from(eui64: Eui64) -> Self {
+        MacAddr8::from(eui64.0)
+    }
+}
+
+impl From<Eui48> for MacAddr {
+    fn from(eui48: Eui48) -> Self {
+        MacAddr::V6(eui48.into())
+    }
+}
+
+impl From<Eui64> for MacAddr {
+    fn from(eui64: Eui64) -> Self {
+        MacAddr::V8(eui64.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Eui48, Eui64};
+    use ::macaddr::{MacAddr, MacAddr6, MacAddr8};
+
+    #[test]
+    fn test_macaddr6_conversion() {
+        let eui48 = Eui48::from(85204980412143);
+        let address = MacAddr6::new(0x4d, 0x7e, 0x54, 0x97, 0x2e, 0xef);
+
+        assert_eq!(MacAddr6::from(eui48), address);
+        assert_eq!(Eui48::from(address), eui48);
+        assert_eq!(MacAddr::from(eui48), MacAddr::V6(address));
+    }
+
+    #[test]
+    fn test_macaddr8_conversion() {
+        let eui64 = Eui64::from(5583992946972634863);
+        let address = MacAddr8::new(0x4d, 0x7e, 0x54, 0, 0, 0x97, 0x2e, 0xef);
+
+        assert_eq!(MacAddr8::from(eui64), address);
+        assert_eq!(Eui64::from(address), eui64);
+        assert_eq!(MacAddr::from(eui64), MacAddr::V8(address));
+    }
+}
@@ -0,0 +1,242 @@
+use crate::{Eui48, EuiFormat, StringToEuiError};
+use core::convert::TryFrom;
+use core::fmt::{self, Display, Formatter};
+
+/// Arbitrary bit mask over `Eui48`, matching addresses equal to pattern in every bit set
+/// in mask, like switch ACL and packet classifier entries.
+///
+/// Textual form is `pattern/mask`, e.g. `4D-7E-54-00-00-00/FF-FF-FF-00-00-00`, mask may be
+/// given as prefix length, e.g. `4D-7E-54-00-00-00/24`.
+#[derive(Eq, PartialEq, Copy, Clone, Hash, Debug)]
+pub struct Eui48Mask {
+    pattern: Eui48,
+    mask: Eui48,
+}
+
+/// Error returned when `pattern/mask` string can't be parsed.
+#[derive(Debug, PartialEq, Eq)]
+pub enum MaskError {
+    /// No `/` between pattern and mask.
+    MissingSlash,
+    /// Pattern is not a valid eui, positions are relative to the whole input.
+    InvalidPattern(StringToEuiError),
+    /// Mask is neither a valid eui nor prefix length, positions are relative to the mask start.
+    InvalidMask(StringToEuiError),
+    /// Prefix length is longer than 48 bits.
+    InvalidPrefixLength { length: usize },
+}
+
+impl Display for MaskError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            MaskError::MissingSlash => f.write_str("missing '/' between pattern and mask"),
+            MaskError::InvalidPattern(error) => write!(f, "invalid pattern: {}", error),
+            MaskError::InvalidMask(error) => write!(f, "invalid mask: {}", error),
+            MaskError::InvalidPrefixLength { length } => {
+                write!(f, "prefix length {} is longer than 48 bits", length)
+            }
+        }
+    }
+}
+
+impl core::error::Error for MaskError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            MaskError::InvalidPattern(error) | MaskError::InvalidMask(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl Eui48Mask {
+    /// Creates mask, bits of pattern outside of mask are cleared.
+    pub fn new(pattern: Eui48, mask: Eui48) -> Self {
+        let mut masked = pattern;
+
+        for (octet, mask) in masked.0.iter_mut().zip(mask.0.iter()) {
+            *octet &= mask;
+        }
+
+        Eui48Mask {
+            pattern: masked,
+            mask,
+        }
+    }
+
+    /// Creates mask matching first `length` bits of pattern, `None` if `length` exceeds 48.
+    pub fn from_prefix(pattern: Eui48, length: u8) -> Option<Self> {
+        if length > 48 {
+            return None;
+        }
+
+        let mask = u64::MAX.checked_shl(48 - length as u32).unwrap_or(0);
+
+        Some(Eui48Mask::new(pattern, Eui48::from(mask)))
+    }
+
+    /// Returns pattern with bits outside of mask cleared.
+    #[inline]
+    pub fn pattern(&self) -> Eui48 {
+        self.pattern
+    }
+
+    /// Returns mask.
+    #[inline]
+    pub fn mask(&self) -> Eui48 {
+        self.mask
+    }
+
+    /// Returns `true` if eui equals pattern in every bit set in mask.
+    pub fn matches(&self, eui: &Eui48) -> bool {
+        eui.0
+            .iter()
+            .zip(self.mask.0.iter())
+            .zip(self.pattern.0.iter())
+            .all(|((octet, mask), pattern)| octet & mask == *pattern)
+    }
+
+    pub(crate) fn display_with(&self, format: EuiFormat) -> impl Display + '_ {
+        FormattedMask { mask: self, format }
+    }
+}
+
+struct FormattedMask<'a> {
+    mask: &'a Eui48Mask,
+    format: EuiFormat,
+}
+
+impl Display for FormattedMask<'_> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}/{}",
+            self.mask.pattern.format_with(self.format),
+            self.mask.mask.format_with(self.format)
+        )
+    }
+}
+
+/// Alternate flag (`{:#}`) prints lowercase colon separated form.
+impl Display for Eui48Mask {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        if f.alternate() {
+            self.display_with(EuiFormat::COLON_LOWER).fmt(f)
+        } else {
+            self.display_with(EuiFormat::CANONICAL).fmt(f)
+        }
+    }
+}
+
+impl TryFrom<&str> for Eui48Mask {
+    type Error = MaskError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let (pattern, mask) = match value.find('/') {
+            Some(slash) => (&value[..slash], &value[slash + 1..]),
+            None => return Err(MaskError::MissingSlash),
+        };
+
+        let pattern = Eui48::try_from(pattern).map_err(MaskError::InvalidPattern)?;
+
+        if !mask.is_empty() && mask.len() <= 2 && mask.bytes().all(|b| b.is_ascii_digit()) {
+            let length = mask
+                .bytes()
+                .fold(0, |acc, b| acc * 10 + (b - b'0') as usize);
+
+            return Eui48Mask::from_prefix(pattern, length as u8)
+                .ok_or(MaskError::InvalidPrefixLength { length });
+        }
+
+        let mask = Eui48::try_from(mask).map_err(MaskError::InvalidMask)?;
+
+        Ok(Eui48Mask::new(pattern, mask))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use crate::{Eui48, Eui48Mask, MaskError, StringToEuiError};
+    use core::convert::TryFrom;
+    use std::format;
+
+    #[test]
+    fn test_mask_error_display() {
+        let error = MaskError::InvalidMask(StringToEuiError::InvalidLength { length: 3 });
+
+        assert_eq!(
+            format!("{}", error),
+            "invalid mask: invalid eui string length 3"
+        );
+        assert!(core::error::Error::source(&error).is_some());
+        assert_eq!(
+            format!("{}", MaskError::InvalidPrefixLength { length: 49 }),
+            "prefix length 49 is longer than 48 bits"
+        );
+        assert!(core::error::Error::source(&MaskError::MissingSlash).is_none());
+    }
+
+    #[test]
+    fn test_mask_matches() {
+        // Every address with the multicast bit set.
+        let multicast =
+            Eui48Mask::new(Eui48::from(0x0100_0000_0000), Eui48::from(0x0100_0000_0000));
+
+        assert!(multicast.matches(&Eui48::from(0x0100_5E00_0001)));
+        assert!(multicast.matches(&Eui48::from(0xFFFF_FFFF_FFFF)));
+        assert!(!multicast.matches(&Eui48::from(85204980412143 & !0x0100_0000_0000)));
+
+        let vendor = Eui48Mask::from_prefix(Eui48::from(85204980412143), 24).unwrap();
+
+        assert_eq!(vendor.pattern(), Eui48::from(0x4D7E_5400_0000));
+        assert!(vendor.matches(&Eui48::from(0x4D7E_54FF_FFFF)));
+        assert!(!vendor.matches(&Eui48::from(0x4D7E_5500_0000)));
+        assert!(Eui48Mask::from_prefix(Eui48::from(1), 0)
+            .unwrap()
+            .matches(&Eui48::from(2)));
+        assert_eq!(Eui48Mask::from_prefix(Eui48::from(1), 49), None);
+    }
+
+    #[test]
+    fn test_mask_from_str() {
+        let vendor = Eui48Mask::from_prefix(Eui48::from(0x4D7E_5400_0000), 24).unwrap();
+
+        assert_eq!(
+            Eui48Mask::try_from("4d:7e:54:00:00:00/ff:ff:ff:00:00:00"),
+            Ok(vendor)
+        );
+        assert_eq!(Eui48Mask::try_from("4D-7E-54-97-2E-EF/24"), Ok(vendor));
+        assert_eq!(
+            Eui48Mask::try_from("4D-7E-54-97-2E-EF"),
+            Err(MaskError::MissingSlash)
+        );
+        assert_eq!(
+            Eui48Mask::try_from("4D-7E-54-97-2E-EF/49"),
+            Err(MaskError::InvalidPrefixLength { length: 49 })
+        );
+        assert_eq!(
+            Eui48Mask::try_from("4D-7E-54/24"),
+            Err(MaskError::InvalidPattern(StringToEuiError::InvalidLength {
+                length: 8
+            }))
+        );
+        assert_eq!(
+            Eui48Mask::try_from("4D-7E-54-97-2E-EF/ff"),
+            Err(MaskError::InvalidMask(StringToEuiError::InvalidLength {
+                length: 2
+            }))
+        );
+    }
+
+    #[test]
+    fn test_mask_display() {
+        let vendor = Eui48Mask::from_prefix(Eui48::from(0x4D7E_5400_0000), 24).unwrap();
+
+        assert_eq!(format!("{}", vendor), "4D-7E-54-00-00-00/FF-FF-FF-00-00-00");
+        assert_eq!(
+            format!("{:#}", vendor),
+            "4d:7e:54:00:00:00/ff:ff:ff:00:00:00"
+        );
+    }
+}
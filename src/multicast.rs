@@ -0,0 +1,178 @@
+use crate::{Eui, Eui48};
+use core::net::{Ipv4Addr, Ipv6Addr};
+
+impl Eui48 {
+    /// Maps IPv4 multicast group to ethernet multicast address as described in RFC 1112,
+    /// `None` if address is not multicast.
+    ///
+    /// Low 23 bits of the group are placed into the `01-00-5E-00-00-00` block.
+    pub fn from_ipv4_multicast(addr: Ipv4Addr) -> Option<Self> {
+        if !addr.is_multicast() {
+            return None;
+        }
+
+        let octets = addr.octets();
+
+        Some(Eui([
+            0x01,
+            0x00,
+            0x5E,
+            octets[1] & 0x7F,
+            octets[2],
+            octets[3],
+        ]))
+    }
+
+    /// Maps IPv6 multicast group to ethernet multicast address as described in RFC 2464,
+    /// `None` if address is not multicast.
+    ///
+    /// Last 32 bits of the group are placed into the `33-33-00-00-00-00` block.
+    pub fn from_ipv6_multicast(addr: Ipv6Addr) -> Option<Self> {
+        if !addr.is_multicast() {
+            return None;
+        }
+
+        let octets = addr.octets();
+
+        Some(Eui([
+            0x33, 0x33, octets[12], octets[13], octets[14], octets[15],
+        ]))
+    }
+
+    /// Returns ethernet address of the solicited-node multicast group of unicast address
+    /// used by IPv6 Neighbor Discovery (`ff02::1:ffXX:XXXX` mapped to `33-33-FF-XX-XX-XX`).
+    pub fn solicited_node_for(addr: Ipv6Addr) -> Self {
+        let octets = addr.octets();
+
+        Eui([0x33, 0x33, 0xFF, octets[13], octets[14], octets[15]])
+    }
+
+    /// Returns low 23 bits of IPv4 multicast group mapped into `01-00-5E` address.
+    pub fn ipv4_multicast_bits(&self) -> Option<u32> {
+        let data = self.0;
+
+        if data[0] != 0x01 || data[1] != 0x00 || data[2] != 0x5E || data[3] & 0x80 != 0 {
+            return None;
+        }
+
+        Some(((data[3] as u32) << 16) | ((data[4] as u32) << 8) | (data[5] as u32))
+    }
+
+    /// Returns iterator over all 32 IPv4 multicast groups that map to this address.
+    pub fn ipv4_multicast_groups(&self) -> Option<Ipv4MulticastGroups> {
+        self.ipv4_multicast_bits()
+            .map(|bits| Ipv4MulticastGroups { bits, index: 0 })
+    }
+}
+
+/// Iterator over IPv4 multicast groups sharing the same ethernet address.
+#[derive(Clone, Debug)]
+pub struct Ipv4MulticastGroups {
+    bits: u32,
+    index: u32,
+}
+
+const IPV4_MULTICAST_GROUPS_PER_ADDRESS: u32 = 32;
+
+impl Iterator for Ipv4MulticastGroups {
+    type Item = Ipv4Addr;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= IPV4_MULTICAST_GROUPS_PER_ADDRESS {
+            return None;
+        }
+
+        let group = 0xE000_0000 | (self.index << 23) | self.bits;
+        self.index += 1;
+
+        Some(Ipv4Addr::from(group))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = (IPV4_MULTICAST_GROUPS_PER_ADDRESS - self.index) as usize;
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for Ipv4MulticastGroups {}
+
+#[cfg(test)]
+mod tests {
+    use crate::Eui48;
+    use core::net::{Ipv4Addr, Ipv6Addr};
+
+    #[test]
+    fn test_eui48_from_ipv4_multicast() {
+        let eui48 = Eui48::from_ipv4_multicast(Ipv4Addr::new(224, 0, 0, 251)).unwrap();
+        assert_eq!(eui48.to_canonical(), "01-00-5E-00-00-FB");
+    }
+
+    #[test]
+    fn test_eui48_from_ipv4_multicast_drops_high_bit() {
+        let eui48 = Eui48::from_ipv4_multicast(Ipv4Addr::new(239, 255, 255, 250)).unwrap();
+        assert_eq!(eui48.to_canonical(), "01-00-5E-7F-FF-FA");
+
+        let eui48 = Eui48::from_ipv4_multicast(Ipv4Addr::new(239, 127, 255, 250)).unwrap();
+        assert_eq!(eui48.to_canonical(), "01-00-5E-7F-FF-FA");
+    }
+
+    #[test]
+    fn test_eui48_from_ipv4_unicast() {
+        assert_eq!(Eui48::from_ipv4_multicast(Ipv4Addr::new(10, 0, 0, 1)), None);
+        assert_eq!(Eui48::from_ipv4_multicast(Ipv4Addr::BROADCAST), None);
+    }
+
+    #[test]
+    fn test_eui48_from_ipv6_multicast() {
+        let eui48 = Eui48::from_ipv6_multicast(Ipv6Addr::new(0xff02, 0, 0, 0, 0, 0, 0, 0xfb));
+        assert_eq!(eui48.unwrap().to_canonical(), "33-33-00-00-00-FB");
+
+        let eui48 =
+            Eui48::from_ipv6_multicast(Ipv6Addr::new(0xff05, 0, 0, 0, 0, 0, 0x4d7e, 0x2eef));
+        assert_eq!(eui48.unwrap().to_canonical(), "33-33-4D-7E-2E-EF");
+
+        let unicast = Ipv6Addr::new(0xfe80, 0, 0, 0, 0, 0, 0x4d7e, 0x2eef);
+        assert_eq!(Eui48::from_ipv6_multicast(unicast), None);
+    }
+
+    #[test]
+    fn test_eui48_solicited_node_for() {
+        let addr = Ipv6Addr::new(0xfe80, 0, 0, 0, 0x4f7e, 0x54ff, 0xfe97, 0x2eef);
+        let eui48 = Eui48::solicited_node_for(addr);
+
+        assert_eq!(eui48.to_canonical(), "33-33-FF-97-2E-EF");
+        assert_eq!(
+            Some(eui48),
+            Eui48::from_ipv6_multicast(Ipv6Addr::new(0xff02, 0, 0, 0, 0, 1, 0xff97, 0x2eef))
+        );
+    }
+
+    #[test]
+    fn test_eui48_ipv4_multicast_bits() {
+        let eui48 = Eui48::from_ipv4_multicast(Ipv4Addr::new(239, 255, 255, 250)).unwrap();
+        assert_eq!(eui48.ipv4_multicast_bits(), Some(0x7FFFFA));
+
+        assert_eq!(Eui48::from(85204980412143).ipv4_multicast_bits(), None);
+        assert_eq!(Eui48::from(0x01005E800000).ipv4_multicast_bits(), None);
+    }
+
+    #[test]
+    fn test_eui48_ipv4_multicast_groups() {
+        let eui48 = Eui48::from_ipv4_multicast(Ipv4Addr::new(224, 0, 0, 251)).unwrap();
+        let mut groups = eui48.ipv4_multicast_groups().unwrap();
+
+        assert_eq!(groups.len(), 32);
+        assert_eq!(groups.next(), Some(Ipv4Addr::new(224, 0, 0, 251)));
+        assert_eq!(groups.next(), Some(Ipv4Addr::new(224, 128, 0, 251)));
+        assert_eq!(groups.last(), Some(Ipv4Addr::new(239, 128, 0, 251)));
+
+        assert!(eui48
+            .ipv4_multicast_groups()
+            .unwrap()
+            .all(|group| Eui48::from_ipv4_multicast(group) == Some(eui48)));
+
+        assert!(Eui48::from(85204980412143)
+            .ipv4_multicast_groups()
+            .is_none());
+    }
+}
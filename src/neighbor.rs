@@ -0,0 +1,190 @@
+use crate::Eui48;
+use core::net::Ipv4Addr;
+
+#[derive(Copy, Clone)]
+struct Entry {
+    ip: Ipv4Addr,
+    eui: Eui48,
+    used: u64,
+}
+
+/// Fixed-capacity IPv4 neighbor cache mapping addresses in both directions, evicting least
+/// recently used entry when full.
+///
+/// # Example
+///
+/// ```rust
+/// use core::net::Ipv4Addr;
+/// use eui::{Eui48, NeighborTable};
+///
+/// let mut neighbors: NeighborTable<8> = NeighborTable::new();
+/// neighbors
+///     .insert(Ipv4Addr::new(192, 168, 1, 1), Eui48::from(85204980412143))
+///     .unwrap();
+///
+/// assert_eq!(
+///     neighbors.eui_of(Ipv4Addr::new(192, 168, 1, 1)),
+///     Some(Eui48::from(85204980412143))
+/// );
+/// assert_eq!(
+///     neighbors.ip_of(Eui48::from(85204980412143)),
+///     Some(Ipv4Addr::new(192, 168, 1, 1))
+/// );
+/// ```
+pub struct NeighborTable<const N: usize> {
+    entries: [Option<Entry>; N],
+    clock: u64,
+}
+
+impl<const N: usize> NeighborTable<N> {
+    /// Creates empty table.
+    pub const fn new() -> Self {
+        NeighborTable {
+            entries: [None; N],
+            clock: 0,
+        }
+    }
+
+    /// Returns number of neighbors.
+    pub fn len(&self) -> usize {
+        self.entries.iter().flatten().count()
+    }
+
+    /// Returns `true` if table has no neighbors.
+    pub fn is_empty(&self) -> bool {
+        self.entries.iter().all(Option::is_none)
+    }
+
+    fn tick(&mut self) -> u64 {
+        self.clock += 1;
+        self.clock
+    }
+
+    /// Maps `ip` to `eui`, replacing previous mapping of `ip`.
+    ///
+    /// When table is full least recently used neighbor is evicted and returned. Zero capacity
+    /// table stores nothing and gives the mapping back as error.
+    pub fn insert(
+        &mut self,
+        ip: Ipv4Addr,
+        eui: Eui48,
+    ) -> Result<Option<(Ipv4Addr, Eui48)>, (Ipv4Addr, Eui48)> {
+        let used = self.tick();
+
+        if let Some(entry) = self.entries.iter_mut().flatten().find(|e| e.ip == ip) {
+            entry.eui = eui;
+            entry.used = used;
+            return Ok(None);
+        }
+
+        // Free slots sort before any used entry.
+        let slot = self
+            .entries
+            .iter_mut()
+            .min_by_key(|slot| slot.map(|e| e.used));
+
+        match slot {
+            Some(slot) => Ok(slot
+                .replace(Entry { ip, eui, used })
+                .map(|evicted| (evicted.ip, evicted.eui))),
+            None => Err((ip, eui)),
+        }
+    }
+
+    /// Returns eui of `ip`, marking neighbor as recently used.
+    pub fn eui_of(&mut self, ip: Ipv4Addr) -> Option<Eui48> {
+        let used = self.tick();
+        let entry = self.entries.iter_mut().flatten().find(|e| e.ip == ip)?;
+        entry.used = used;
+
+        Some(entry.eui)
+    }
+
+    /// Returns most recently used ip of `eui`, marking neighbor as recently used.
+    pub fn ip_of(&mut self, eui: Eui48) -> Option<Ipv4Addr> {
+        let used = self.tick();
+        let entry = self
+            .entries
+            .iter_mut()
+            .flatten()
+            .filter(|e| e.eui == eui)
+            .max_by_key(|e| e.used)?;
+        entry.used = used;
+
+        Some(entry.ip)
+    }
+
+    /// Removes neighbor with `ip`, returning its eui.
+    pub fn remove(&mut self, ip: Ipv4Addr) -> Option<Eui48> {
+        self.entries
+            .iter_mut()
+            .find(|slot| slot.is_some_and(|e| e.ip == ip))
+            .and_then(Option::take)
+            .map(|e| e.eui)
+    }
+
+    /// Returns iterator over neighbors.
+    pub fn iter(&self) -> impl Iterator<Item = (Ipv4Addr, Eui48)> + '_ {
+        self.entries.iter().flatten().map(|e| (e.ip, e.eui))
+    }
+}
+
+impl<const N: usize> Default for NeighborTable<N> {
+    fn default() -> Self {
+        NeighborTable::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Eui48, NeighborTable};
+    use core::net::Ipv4Addr;
+
+    #[test]
+    fn test_neighbor_lookup() {
+        let mut table: NeighborTable<4> = NeighborTable::new();
+        let eui48 = Eui48::from(85204980412143);
+
+        assert_eq!(table.insert(Ipv4Addr::new(10, 0, 0, 1), eui48), Ok(None));
+        assert_eq!(table.insert(Ipv4Addr::new(10, 0, 0, 2), eui48), Ok(None));
+        assert_eq!(
+            table.insert(Ipv4Addr::new(10, 0, 0, 1), Eui48::from(1)),
+            Ok(None)
+        );
+
+        assert_eq!(
+            table.eui_of(Ipv4Addr::new(10, 0, 0, 1)),
+            Some(Eui48::from(1))
+        );
+        assert_eq!(table.ip_of(eui48), Some(Ipv4Addr::new(10, 0, 0, 2)));
+        assert_eq!(table.ip_of(Eui48::from(2)), None);
+        assert_eq!(table.remove(Ipv4Addr::new(10, 0, 0, 2)), Some(eui48));
+        assert_eq!(table.len(), 1);
+    }
+
+    #[test]
+    fn test_neighbor_eviction() {
+        let mut table: NeighborTable<2> = NeighborTable::new();
+
+        table
+            .insert(Ipv4Addr::new(10, 0, 0, 1), Eui48::from(1))
+            .unwrap();
+        table
+            .insert(Ipv4Addr::new(10, 0, 0, 2), Eui48::from(2))
+            .unwrap();
+        table.eui_of(Ipv4Addr::new(10, 0, 0, 1));
+
+        assert_eq!(
+            table.insert(Ipv4Addr::new(10, 0, 0, 3), Eui48::from(3)),
+            Ok(Some((Ipv4Addr::new(10, 0, 0, 2), Eui48::from(2))))
+        );
+        assert_eq!(table.iter().count(), 2);
+
+        let mut empty: NeighborTable<0> = NeighborTable::new();
+        assert_eq!(
+            empty.insert(Ipv4Addr::new(10, 0, 0, 1), Eui48::from(1)),
+            Err((Ipv4Addr::new(10, 0, 0, 1), Eui48::from(1)))
+        );
+        assert!(empty.is_empty());
+    }
+}
@@ -0,0 +1,264 @@
+use crate::Eui;
+use core::iter::Copied;
+use core::ops::{Index, IndexMut};
+use core::slice::Iter;
+
+impl<const N: usize> Eui<N> {
+    /// Returns octet at index, `None` if out of bounds.
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<u8> {
+        self.0.get(index).copied()
+    }
+
+    /// Returns bit `n` in IEEE transmission order, bit 0 is I/G and bit 1 is U/L.
+    ///
+    /// Panics if `n` is out of bounds.
+    #[inline]
+    pub fn get_bit(&self, n: usize) -> bool {
+        self.0[n / 8] & (1 << (n % 8)) != 0
+    }
+
+    /// Sets bit `n` in IEEE transmission order.
+    ///
+    /// Panics if `n` is out of bounds.
+    #[inline]
+    pub fn set_bit(&mut self, n: usize, value: bool) {
+        if value {
+            self.0[n / 8] |= 1 << (n % 8);
+        } else {
+            self.0[n / 8] &= !(1 << (n % 8));
+        }
+    }
+
+    /// Returns copy with bit `n` in IEEE transmission order set to value.
+    ///
+    /// Panics if `n` is out of bounds.
+    #[inline]
+    pub fn with_bit(mut self, n: usize, value: bool) -> Self {
+        self.set_bit(n, value);
+        self
+    }
+
+    /// Returns octets in non-canonical (bit-reversed, Token Ring) order.
+    #[inline]
+    pub fn to_bit_reversed(&self) -> [u8; N] {
+        let mut result = self.0;
+
+        for byte in result.iter_mut() {
+            *byte = byte.reverse_bits();
+        }
+
+        result
+    }
+
+    /// Creates eui from octets in non-canonical (bit-reversed, Token Ring) order.
+    #[inline]
+    pub fn from_bit_reversed(bytes: [u8; N]) -> Self {
+        Eui(Eui(bytes).to_bit_reversed())
+    }
+
+    /// Returns octets in reversed order as stored by some ethernet peripherals.
+    #[inline]
+    pub fn reverse_octets(&self) -> [u8; N] {
+        let mut result = self.0;
+        result.reverse();
+
+        result
+    }
+
+    /// Creates eui from octets stored in reversed order.
+    #[inline]
+    pub fn from_reversed_bytes(bytes: [u8; N]) -> Self {
+        Eui(Eui(bytes).reverse_octets())
+    }
+
+    /// Reinterprets octets in place as eui without copying.
+    #[inline]
+    pub fn from_bytes_ref(bytes: &[u8; N]) -> &Self {
+        // Safety: `Eui` is `#[repr(transparent)]` over `[u8; N]`.
+        unsafe { &*(bytes as *const [u8; N] as *const Eui<N>) }
+    }
+
+    /// Reinterprets octets in place as mutable eui without copying.
+    #[inline]
+    pub fn from_bytes_mut(bytes: &mut [u8; N]) -> &mut Self {
+        // Safety: `Eui` is `#[repr(transparent)]` over `[u8; N]`.
+        unsafe { &mut *(bytes as *mut [u8; N] as *mut Eui<N>) }
+    }
+}
+
+impl<const N: usize> Index<usize> for Eui<N> {
+    type Output = u8;
+
+    #[inline]
+    fn index(&self, index: usize) -> &u8 {
+        &self.0[index]
+    }
+}
+
+impl<const N: usize> IndexMut<usize> for Eui<N> {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut u8 {
+        &mut self.0[index]
+    }
+}
+
+impl<const N: usize> IntoIterator for Eui<N> {
+    type Item = u8;
+    type IntoIter = core::array::IntoIter<u8, N>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIterator::into_iter(self.0)
+    }
+}
+
+impl<'a, const N: usize> IntoIterator for &'a Eui<N> {
+    type Item = u8;
+    type IntoIter = Copied<Iter<'a, u8>>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter().copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Eui48, Eui64};
+
+    #[test]
+    fn test_eui48_bit_reversed() {
+        let eui48 = Eui48::from(85204980412143);
+        let reversed = eui48.to_bit_reversed();
+
+        assert_eq!(reversed, [0xB2, 0x7E, 0x2A, 0xE9, 0x74, 0xF7]);
+        assert_eq!(Eui48::from_bit_reversed(reversed), eui48);
+    }
+
+    #[test]
+    fn test_eui64_bit_reversed() {
+        let eui64 = Eui64::from(5583992946972634863);
+        let reversed = eui64.to_bit_reversed();
+
+        assert_eq!(reversed, [0xB2, 0x7E, 0x2A, 0x00, 0x00, 0xE9, 0x74, 0xF7]);
+        assert_eq!(Eui64::from_bit_reversed(reversed), eui64);
+    }
+
+    #[test]
+    fn test_eui48_reverse_octets() {
+        let eui48 = Eui48::from(85204980412143);
+        let reversed = eui48.reverse_octets();
+
+        assert_eq!(reversed, [0xEF, 0x2E, 0x97, 0x54, 0x7E, 0x4D]);
+        assert_eq!(Eui48::from_reversed_bytes(reversed), eui48);
+    }
+
+    #[test]
+    fn test_eui64_reverse_octets() {
+        let eui64 = Eui64::from(5583992946972634863);
+        let reversed = eui64.reverse_octets();
+
+        assert_eq!(reversed, [0xEF, 0x2E, 0x97, 0x00, 0x00, 0x54, 0x7E, 0x4D]);
+        assert_eq!(Eui64::from_reversed_bytes(reversed), eui64);
+    }
+
+    #[test]
+    fn test_eui48_from_bytes_ref() {
+        let mut frame = [0x4d, 0x7e, 0x54, 0x97, 0x2e, 0xef];
+
+        assert_eq!(*Eui48::from_bytes_ref(&frame), Eui48::from(85204980412143));
+
+        *Eui48::from_bytes_mut(&mut frame) = Eui48::from(1);
+        assert_eq!(frame, [0, 0, 0, 0, 0, 1]);
+    }
+
+    #[test]
+    fn test_eui64_from_bytes_ref() {
+        let mut frame = [0x4d, 0x7e, 0x54, 0, 0, 0x97, 0x2e, 0xef];
+
+        assert_eq!(
+            *Eui64::from_bytes_ref(&frame),
+            Eui64::from(5583992946972634863)
+        );
+
+        *Eui64::from_bytes_mut(&mut frame) = Eui64::from(1);
+        assert_eq!(frame, [0, 0, 0, 0, 0, 0, 0, 1]);
+    }
+
+    #[test]
+    fn test_eui48_index() {
+        let mut eui48 = Eui48::from(85204980412143);
+
+        assert_eq!(eui48[0], 0x4D);
+        assert_eq!(eui48.get(5), Some(0xEF));
+        assert_eq!(eui48.get(6), None);
+
+        eui48[5] = 0x01;
+        assert_eq!(eui48, Eui48::from(0x4D7E_5497_2E01));
+    }
+
+    #[test]
+    fn test_eui64_index() {
+        let mut eui64 = Eui64::from(5583992946972634863);
+
+        assert_eq!(eui64[3], 0x00);
+        assert_eq!(eui64.get(7), Some(0xEF));
+        assert_eq!(eui64.get(8), None);
+
+        eui64[3] = 0xFF;
+        assert_eq!(eui64, Eui64::from(0x4D7E_54FF_0097_2EEF));
+    }
+
+    #[test]
+    fn test_eui48_into_iter() {
+        let eui48 = Eui48::from(85204980412143);
+        let mut frame = [0u8; 12];
+
+        for (slot, octet) in frame.iter_mut().zip(eui48.into_iter().chain(&eui48)) {
+            *slot = octet;
+        }
+
+        assert_eq!(frame[..6], frame[6..]);
+        assert_eq!(frame[..6], [0x4D, 0x7E, 0x54, 0x97, 0x2E, 0xEF]);
+    }
+
+    #[test]
+    fn test_eui64_into_iter() {
+        let eui64 = Eui64::from(5583992946972634863);
+
+        assert_eq!((&eui64).into_iter().count(), 8);
+        assert_eq!(eui64.into_iter().map(u64::from).sum::<u64>(), 0x2D3);
+    }
+
+    #[test]
+    fn test_eui48_bits() {
+        let eui48 = Eui48::from(0x4C7E_5497_2EEF);
+
+        assert!(!eui48.get_bit(0));
+        assert!(!eui48.get_bit(1));
+        assert!(eui48.get_bit(47));
+
+        let local = eui48.with_bit(1, true);
+        assert_eq!(local, Eui48::from(0x4E7E_5497_2EEF));
+        assert_eq!(local.with_bit(1, false), eui48);
+
+        let mut multicast = eui48;
+        multicast.set_bit(0, true);
+        assert_eq!(multicast, Eui48::from(0x4D7E_5497_2EEF));
+    }
+
+    #[test]
+    fn test_eui64_bits() {
+        let eui64 = Eui64::from(0).with_bit(63, true).with_bit(8, true);
+
+        assert_eq!(eui64, Eui64::from(0x0001_0000_0000_0080));
+        assert!(eui64.get_bit(63));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_eui48_bit_out_of_bounds() {
+        Eui48::from(0).get_bit(48);
+    }
+}
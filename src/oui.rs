@@ -0,0 +1,235 @@
+use crate::format::FormattedEui48;
+use crate::{check_string_length, string_to_eui, Eui, Eui48, Eui64, EuiFormat, StringToEuiError};
+use core::convert::TryFrom;
+use core::fmt::{Debug, Display, Error, Formatter};
+
+/// Organizationally unique identifier, first three octets of eui assigned to a vendor.
+#[derive(Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Hash, Default)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy::AsBytes,
+        zerocopy::FromBytes,
+        zerocopy::FromZeroes,
+        zerocopy::Unaligned
+    )
+)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    rkyv(compare(PartialEq), derive(Debug))
+)]
+#[repr(transparent)]
+pub struct Oui(pub(crate) [u8; 3]);
+
+pub(crate) const OUI_STRING_LENGTHS: [usize; 2] = [6, 8];
+
+impl Oui {
+    /// Creates oui from its three octets.
+    #[inline]
+    pub const fn new(octets: [u8; 3]) -> Self {
+        Oui(octets)
+    }
+
+    /// Returns three octets of oui.
+    #[inline]
+    pub const fn octets(&self) -> [u8; 3] {
+        self.0
+    }
+
+    /// Returns oui in requested format.
+    #[inline]
+    pub(crate) fn format_with(&self, format: EuiFormat) -> FormattedEui48 {
+        FormattedEui48::new(&self.0, format)
+    }
+}
+
+impl Eui48 {
+    /// Returns vendor prefix of eui.
+    #[inline]
+    pub fn oui(&self) -> Oui {
+        Oui([self.0[0], self.0[1], self.0[2]])
+    }
+
+    /// Splits eui into vendor prefix and network interface specific octets.
+    #[inline]
+    pub fn split(&self) -> (Oui, [u8; 3]) {
+        (self.oui(), [self.0[3], self.0[4], self.0[5]])
+    }
+
+    /// Creates eui from vendor prefix and network interface specific octets.
+    #[inline]
+    pub const fn from_parts(oui: Oui, nic: [u8; 3]) -> Self {
+        Eui([oui.0[0], oui.0[1], oui.0[2], nic[0], nic[1], nic[2]])
+    }
+
+    /// Returns copy of eui with vendor prefix replaced.
+    #[inline]
+    pub fn with_oui(self, oui: Oui) -> Self {
+        let (_, nic) = self.split();
+
+        Eui48::from_parts(oui, nic)
+    }
+
+    /// Returns copy of eui with network interface specific octets replaced.
+    #[inline]
+    pub fn with_nic(self, nic: [u8; 3]) -> Self {
+        Eui48::from_parts(self.oui(), nic)
+    }
+}
+
+impl Eui64 {
+    /// Returns vendor prefix of eui.
+    #[inline]
+    pub fn oui(&self) -> Oui {
+        Oui([self.0[0], self.0[1], self.0[2]])
+    }
+
+    /// Splits eui into vendor prefix and extension identifier.
+    #[inline]
+    pub fn split(&self) -> (Oui, [u8; 5]) {
+        let mut extension = [0; 5];
+        extension.copy_from_slice(&self.0[3..]);
+
+        (self.oui(), extension)
+    }
+
+    /// Creates eui from vendor prefix and extension identifier.
+    #[inline]
+    pub const fn from_parts(oui: Oui, extension: [u8; 5]) -> Self {
+        let [a, b, c] = oui.0;
+        let [d, e, f, g, h] = extension;
+
+        Eui([a, b, c, d, e, f, g, h])
+    }
+
+    /// Creates eui from vendor prefix and serial number stored in low 32 bits of extension.
+    #[inline]
+    pub const fn from_serial(oui: Oui, serial: u32) -> Self {
+        let [d, e, f, g] = serial.to_be_bytes();
+
+        Eui64::from_parts(oui, [0, d, e, f, g])
+    }
+
+    /// Returns copy of eui with vendor prefix replaced.
+    #[inline]
+    pub fn with_oui(self, oui: Oui) -> Self {
+        let (_, extension) = self.split();
+
+        Eui64::from_parts(oui, extension)
+    }
+}
+
+impl From<[u8; 3]> for Oui {
+    fn from(octets: [u8; 3]) -> Self {
+        Oui(octets)
+    }
+}
+
+impl From<Oui> for [u8; 3] {
+    fn from(oui: Oui) -> Self {
+        oui.0
+    }
+}
+
+/// Accepts `4D-7E-54`, `4d:7e:54` or `4d7e54`.
+impl TryFrom<&str> for Oui {
+    type Error = StringToEuiError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        check_string_length(value.len(), &OUI_STRING_LENGTHS)?;
+
+        let mut result = [0; 3];
+        string_to_eui(value, &mut result)?;
+
+        Ok(Oui(result))
+    }
+}
+
+/// Alternate flag (`{:#}`) prints lowercase colon separated form.
+impl Display for Oui {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        if f.alternate() {
+            f.pad(&self.format_with(EuiFormat::COLON_LOWER))
+        } else {
+            f.pad(&self.format_with(EuiFormat::CANONICAL))
+        }
+    }
+}
+
+impl Debug for Oui {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        write!(f, "Oui({})", self.format_with(EuiFormat::CANONICAL))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use crate::{Eui48, Eui64, Oui, StringToEuiError};
+    use core::convert::TryFrom;
+    use std::format;
+
+    #[test]
+    fn test_oui_of_eui() {
+        let oui = Oui::new([0x4d, 0x7e, 0x54]);
+
+        assert_eq!(Eui48::from(85204980412143).oui(), oui);
+        assert_eq!(Eui64::from(5583992946972634863).oui(), oui);
+        assert_eq!(<[u8; 3]>::from(oui), [0x4d, 0x7e, 0x54]);
+    }
+
+    #[test]
+    fn test_oui_from_str() {
+        let oui = Oui::new([0x4d, 0x7e, 0x54]);
+
+        assert_eq!(Oui::try_from("4D-7E-54"), Ok(oui));
+        assert_eq!(Oui::try_from("4d:7e:54"), Ok(oui));
+        assert_eq!(Oui::try_from("4d7e54"), Ok(oui));
+        assert_eq!(
+            Oui::try_from("4d7e5"),
+            Err(StringToEuiError::InvalidLength { length: 5 })
+        );
+        assert_eq!(
+            Oui::try_from("4d:7e-54"),
+            Err(StringToEuiError::OnlyOneSeparatorTypeExpected)
+        );
+    }
+
+    #[test]
+    fn test_oui_display() {
+        let oui = Oui::new([0x4d, 0x7e, 0x54]);
+
+        assert_eq!(format!("{}", oui), "4D-7E-54");
+        assert_eq!(format!("{:#}", oui), "4d:7e:54");
+        assert_eq!(format!("{:?}", oui), "Oui(4D-7E-54)");
+    }
+
+    #[test]
+    fn test_split_and_from_parts() {
+        let oui = Oui::new([0x4d, 0x7e, 0x54]);
+        let eui48 = Eui48::from(85204980412143);
+        let eui64 = Eui64::from(5583992946972634863);
+
+        assert_eq!(eui48.split(), (oui, [0x97, 0x2e, 0xef]));
+        assert_eq!(Eui48::from_parts(oui, [0x97, 0x2e, 0xef]), eui48);
+
+        assert_eq!(eui64.split(), (oui, [0, 0, 0x97, 0x2e, 0xef]));
+        assert_eq!(Eui64::from_parts(oui, [0, 0, 0x97, 0x2e, 0xef]), eui64);
+        assert_eq!(Eui64::from_serial(oui, 0x972eef), eui64);
+    }
+
+    #[test]
+    fn test_with_oui_and_nic() {
+        let oui = Oui::new([0x02, 0x00, 0x5e]);
+        let eui48 = Eui48::from(85204980412143);
+
+        assert_eq!(eui48.with_oui(oui), Eui48::from(0x0200_5E97_2EEF));
+        assert_eq!(eui48.with_nic([0, 0, 1]), Eui48::from(0x4D7E_5400_0001));
+        assert_eq!(
+            Eui64::from(5583992946972634863).with_oui(oui),
+            Eui64::from(0x0200_5E00_0097_2EEF)
+        );
+    }
+}
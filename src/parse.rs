@@ -0,0 +1,865 @@
+use crate::{
+    ascii_to_eui, check_digit_count, check_string_length, string_to_eui, Case, Eui, Eui48, Eui64,
+    EuiFormat, Separator, StringToEuiError, EUI48_STRING_LENGTHS, EUI64_STRING_LENGTHS,
+};
+
+/// Options describing which textual forms are accepted by `parse_with`.
+///
+/// # Example
+///
+/// ```rust
+/// use eui::{Case, Eui48, ParseOptions, Separator};
+///
+/// let options = ParseOptions::new()
+///     .separators(&[Separator::Colon])
+///     .case(Case::Lower);
+///
+/// assert!(Eui48::parse_with("4d:7e:54:97:2e:ef", &options).is_ok());
+/// assert!(Eui48::parse_with("4D:7E:54:97:2E:EF", &options).is_err());
+/// assert!(Eui48::parse_with("4d-7e-54-97-2e-ef", &options).is_err());
+/// ```
+#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+pub struct ParseOptions {
+    separators: u8,
+    group_sizes: u8,
+    case: Option<Case>,
+    strict: bool,
+}
+
+const fn separator_bit(separator: Separator) -> u8 {
+    1 << separator as u8
+}
+
+const ALL_SEPARATORS: u8 = separator_bit(Separator::Dash)
+    | separator_bit(Separator::Colon)
+    | separator_bit(Separator::Dot)
+    | separator_bit(Separator::Space)
+    | separator_bit(Separator::None);
+
+impl ParseOptions {
+    /// Accepts every separator, groups of one or two octets and any case.
+    pub const fn new() -> Self {
+        ParseOptions {
+            separators: ALL_SEPARATORS,
+            group_sizes: 0b11,
+            case: None,
+            strict: true,
+        }
+    }
+
+    /// Limits accepted separators, `Separator::None` allows form without separators.
+    pub const fn separators(mut self, separators: &[Separator]) -> Self {
+        self.separators = 0;
+
+        let mut i = 0;
+        while i < separators.len() {
+            self.separators |= separator_bit(separators[i]);
+            i += 1;
+        }
+
+        self
+    }
+
+    /// Limits accepted number of octets between separators, sizes above 8 are ignored.
+    pub const fn group_sizes(mut self, group_sizes: &[usize]) -> Self {
+        self.group_sizes = 0;
+
+        let mut i = 0;
+        while i < group_sizes.len() {
+            if group_sizes[i] >= 1 && group_sizes[i] <= 8 {
+                self.group_sizes |= 1 << (group_sizes[i] - 1);
+            }
+            i += 1;
+        }
+
+        self
+    }
+
+    /// Requires hexadecimal letters to be in the given case.
+    pub const fn case(mut self, case: Case) -> Self {
+        self.case = Some(case);
+        self
+    }
+
+    /// Strict mode (default) requires single kind of separator placed between equal groups.
+    /// Otherwise surrounding whitespace is trimmed and separators may appear anywhere.
+    pub const fn strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    fn allows_separator(&self, separator: Separator) -> bool {
+        self.separators & separator_bit(separator) != 0
+    }
+
+    fn allows_group_size(&self, group_size: usize) -> bool {
+        (1..=8).contains(&group_size) && self.group_sizes & (1 << (group_size - 1)) != 0
+    }
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        ParseOptions::new()
+    }
+}
+
+impl From<EuiFormat> for ParseOptions {
+    /// Accepts only the exact form written with format.
+    fn from(format: EuiFormat) -> Self {
+        ParseOptions::new()
+            .separators(&[format.separator])
+            .group_sizes(&[format.group_size])
+            .case(format.case)
+    }
+}
+
+/// Textual convention recognized by `parse_detect`.
+///
+/// Input without letters, or with letters of both cases, is reported as upper case.
+#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+pub enum DetectedFormat {
+    /// `4d:7e:54:97:2e:ef`
+    ColonLower,
+    /// `4D:7E:54:97:2E:EF`
+    ColonUpper,
+    /// `4d-7e-54-97-2e-ef`
+    DashLower,
+    /// `4D-7E-54-97-2E-EF`
+    DashUpper,
+    /// `4d 7e 54 97 2e ef`
+    SpaceLower,
+    /// `4D 7E 54 97 2E EF`
+    SpaceUpper,
+    /// `4d7e.5497.2eef`
+    DottedLower,
+    /// `4D7E.5497.2EEF`
+    DottedUpper,
+    /// `4d7e54972eef`
+    BareLower,
+    /// `4D7E54972EEF`
+    BareUpper,
+}
+
+impl DetectedFormat {
+    fn detect(input: &str) -> Self {
+        let lower = input.bytes().any(|b| b.is_ascii_lowercase())
+            && !input.bytes().any(|b| b.is_ascii_uppercase());
+        let separator = input.chars().find_map(Separator::from_char);
+
+        match (separator, lower) {
+            (Some(Separator::Colon), true) => DetectedFormat::ColonLower,
+            (Some(Separator::Colon), false) => DetectedFormat::ColonUpper,
+            (Some(Separator::Dash), true) => DetectedFormat::DashLower,
+            (Some(Separator::Dash), false) => DetectedFormat::DashUpper,
+            (Some(Separator::Space), true) => DetectedFormat::SpaceLower,
+            (Some(Separator::Space), false) => DetectedFormat::SpaceUpper,
+            (Some(Separator::Dot), true) => DetectedFormat::DottedLower,
+            (Some(Separator::Dot), false) => DetectedFormat::DottedUpper,
+            (_, true) => DetectedFormat::BareLower,
+            (_, false) => DetectedFormat::BareUpper,
+        }
+    }
+
+    /// Format which writes eui back in the detected convention.
+    pub fn to_format(self) -> EuiFormat {
+        let (separator, group_size, case) = match self {
+            DetectedFormat::ColonLower => (Separator::Colon, 1, Case::Lower),
+            DetectedFormat::ColonUpper => (Separator::Colon, 1, Case::Upper),
+            DetectedFormat::DashLower => (Separator::Dash, 1, Case::Lower),
+            DetectedFormat::DashUpper => (Separator::Dash, 1, Case::Upper),
+            DetectedFormat::SpaceLower => (Separator::Space, 1, Case::Lower),
+            DetectedFormat::SpaceUpper => (Separator::Space, 1, Case::Upper),
+            DetectedFormat::DottedLower => (Separator::Dot, 2, Case::Lower),
+            DetectedFormat::DottedUpper => (Separator::Dot, 2, Case::Upper),
+            DetectedFormat::BareLower => (Separator::None, 1, Case::Lower),
+            DetectedFormat::BareUpper => (Separator::None, 1, Case::Upper),
+        };
+
+        EuiFormat {
+            separator,
+            group_size,
+            case,
+        }
+    }
+}
+
+fn hex_value(c: char) -> Option<u8> {
+    c.to_digit(16).map(|value| value as u8)
+}
+
+/// Parses colon separated groups of one or two hexadecimal digits as POSIX `ether_aton` does.
+fn ether_aton_to_eui(input: &str, result: &mut [u8]) -> Result<(), StringToEuiError> {
+    if input.is_empty() {
+        return Err(StringToEuiError::InvalidLength { length: 0 });
+    }
+
+    let mut octets = 0;
+    let mut start = 0;
+
+    for group in input.split(':') {
+        if group.is_empty() || group.len() > 2 {
+            return Err(StringToEuiError::InvalidSeparatorPlace {
+                position: start + group.len().min(2),
+            });
+        }
+
+        if octets == result.len() {
+            return Err(StringToEuiError::TrailingCharacters {
+                position: start - 1,
+            });
+        }
+
+        let mut value = 0;
+
+        for (i, c) in group.char_indices() {
+            match hex_value(c) {
+                Some(digit) => value = value << 4 | digit,
+                None => {
+                    return Err(StringToEuiError::InvalidChar {
+                        char: c,
+                        position: start + i,
+                    })
+                }
+            }
+        }
+
+        result[octets] = value;
+        octets += 1;
+        start += group.len() + 1;
+    }
+
+    if octets != result.len() {
+        return Err(StringToEuiError::TooFewOctets {
+            octets,
+            expected: result.len(),
+        });
+    }
+
+    Ok(())
+}
+
+/// Stores digit with given index into result.
+fn push_digit(
+    result: &mut [u8],
+    digits: usize,
+    value: u8,
+    position: usize,
+) -> Result<(), StringToEuiError> {
+    match result.get_mut(digits / 2) {
+        Some(octet) if digits % 2 == 0 => *octet = value << 4,
+        Some(octet) => *octet |= value,
+        None => return Err(StringToEuiError::TrailingCharacters { position }),
+    }
+
+    Ok(())
+}
+
+/// Collects hexadecimal digits skipping surrounding whitespace and any separators.
+fn lenient_to_eui(input: &str, result: &mut [u8]) -> Result<(), StringToEuiError> {
+    let offset = input.len() - input.trim_start().len();
+    let mut digits = 0;
+
+    for (i, c) in input.trim().char_indices() {
+        let value = match hex_value(c) {
+            Some(value) => value,
+            None if c == ':' || c == '-' || c == '.' || c == ' ' => continue,
+            None => {
+                return Err(StringToEuiError::InvalidChar {
+                    char: c,
+                    position: offset + i,
+                })
+            }
+        };
+
+        push_digit(result, digits, value, offset + i)?;
+        digits += 1;
+    }
+
+    check_digit_count(digits, result.len())
+}
+
+fn options_to_eui(
+    input: &str,
+    options: &ParseOptions,
+    result: &mut [u8],
+) -> Result<(), StringToEuiError> {
+    let (offset, input) = if options.strict {
+        (0, input)
+    } else {
+        (input.len() - input.trim_start().len(), input.trim())
+    };
+
+    let mut digits = 0;
+    let mut group_digits = 0;
+    let mut group_size = None;
+    let mut separator_type = None;
+    let mut groups = 1;
+
+    for (i, c) in input.char_indices() {
+        let position = offset + i;
+
+        if let Some(value) = hex_value(c) {
+            let case_matches = match options.case {
+                Some(Case::Upper) => !c.is_ascii_lowercase(),
+                Some(Case::Lower) => !c.is_ascii_uppercase(),
+                None => true,
+            };
+
+            if !case_matches {
+                return Err(StringToEuiError::InvalidChar { char: c, position });
+            }
+
+            push_digit(result, digits, value, position)?;
+            digits += 1;
+            group_digits += 1;
+            continue;
+        }
+
+        let separator = match Separator::from_char(c) {
+            Some(separator) if options.allows_separator(separator) => separator,
+            _ => return Err(StringToEuiError::InvalidChar { char: c, position }),
+        };
+
+        if !options.strict {
+            continue;
+        }
+
+        if let Some(size) = group_size {
+            if digits == result.len() * 2 {
+                return Err(StringToEuiError::GroupCountMismatch {
+                    expected: digits / size,
+                    actual: input
+                        .chars()
+                        .filter(|&c| Separator::from_char(c) == Some(separator))
+                        .count()
+                        + 1,
+                });
+            }
+        }
+
+        match separator_type {
+            Some(t) if t != separator => {
+                return Err(StringToEuiError::OnlyOneSeparatorTypeExpected);
+            }
+            _ => separator_type = Some(separator),
+        }
+
+        // First group decides how many digits every following group must have.
+        match group_size {
+            Some(size) if size == group_digits => {}
+            None if group_digits % 2 == 0 && options.allows_group_size(group_digits / 2) => {
+                group_size = Some(group_digits)
+            }
+            _ => return Err(StringToEuiError::InvalidSeparatorPlace { position }),
+        }
+
+        group_digits = 0;
+        groups += 1;
+    }
+
+    if options.strict {
+        match group_size {
+            // Group count is checked first, so `4d:7e:54:97:2eef` reports a missing group.
+            Some(size) if groups != result.len() * 2 / size => {
+                return Err(StringToEuiError::GroupCountMismatch {
+                    expected: result.len() * 2 / size,
+                    actual: groups,
+                });
+            }
+            Some(size) if size != group_digits => {
+                return Err(StringToEuiError::InvalidSeparatorPlace {
+                    position: offset + input.len(),
+                });
+            }
+            None if !options.allows_separator(Separator::None) => {
+                return Err(StringToEuiError::InvalidSeparatorPlace {
+                    position: offset + input.len(),
+                });
+            }
+            _ => {}
+        }
+    }
+
+    check_digit_count(digits, result.len())
+}
+
+impl Eui48 {
+    /// Parses colon separated form where leading zeros of octets may be omitted (`0:a:b:c:d:e`).
+    pub fn parse_ether_aton(input: &str) -> Result<Self, StringToEuiError> {
+        let mut result = [0; 6];
+        ether_aton_to_eui(input, &mut result)?;
+
+        Ok(Eui(result))
+    }
+
+    /// Parses address ignoring surrounding whitespace, case and kind of separators.
+    ///
+    /// Useful for values read from files like `/sys/class/net/*/address`.
+    pub fn parse_lenient(input: &str) -> Result<Self, StringToEuiError> {
+        let mut result = [0; 6];
+        lenient_to_eui(input, &mut result)?;
+
+        Ok(Eui(result))
+    }
+
+    /// Parses address accepting only forms allowed by options.
+    pub fn parse_with(input: &str, options: &ParseOptions) -> Result<Self, StringToEuiError> {
+        let mut result = [0; 6];
+        options_to_eui(input, options, &mut result)?;
+
+        Ok(Eui(result))
+    }
+
+    /// Parses address accepting only the exact form written with format.
+    pub fn parse_strict(input: &str, format: EuiFormat) -> Result<Self, StringToEuiError> {
+        Eui48::parse_with(input, &ParseOptions::from(format))
+    }
+
+    /// Parses address accepting only canonical IEEE form (`4D-7E-54-97-2E-EF`).
+    pub fn parse_canonical(input: &str) -> Result<Self, StringToEuiError> {
+        Eui48::parse_strict(input, EuiFormat::CANONICAL)
+    }
+
+    /// Parses the same forms as `TryFrom<&str>` from raw bytes without UTF-8 validation.
+    pub fn parse_ascii(input: &[u8]) -> Result<Self, StringToEuiError> {
+        check_string_length(input.len(), &EUI48_STRING_LENGTHS)?;
+
+        let mut result = [0; 6];
+        ascii_to_eui(input, &mut result)?;
+
+        Ok(Eui(result))
+    }
+
+    /// Parses the same forms as `TryFrom<&str>` and reports which convention input used.
+    pub fn parse_detect(input: &str) -> Result<(Self, DetectedFormat), StringToEuiError> {
+        check_string_length(input.len(), &EUI48_STRING_LENGTHS)?;
+
+        let mut result = [0; 6];
+        string_to_eui(input, &mut result)?;
+
+        Ok((Eui(result), DetectedFormat::detect(input)))
+    }
+}
+
+impl Eui64 {
+    /// Parses colon separated form where leading zeros of octets may be omitted (`0:a:b:c:d:e:f:1`).
+    pub fn parse_ether_aton(input: &str) -> Result<Self, StringToEuiError> {
+        let mut result = [0; 8];
+        ether_aton_to_eui(input, &mut result)?;
+
+        Ok(Eui(result))
+    }
+
+    /// Parses address ignoring surrounding whitespace, case and kind of separators.
+    ///
+    /// Useful for values read from files like `/sys/class/net/*/address`.
+    pub fn parse_lenient(input: &str) -> Result<Self, StringToEuiError> {
+        let mut result = [0; 8];
+        lenient_to_eui(input, &mut result)?;
+
+        Ok(Eui(result))
+    }
+
+    /// Parses address accepting only forms allowed by options.
+    pub fn parse_with(input: &str, options: &ParseOptions) -> Result<Self, StringToEuiError> {
+        let mut result = [0; 8];
+        options_to_eui(input, options, &mut result)?;
+
+        Ok(Eui(result))
+    }
+
+    /// Parses address accepting only the exact form written with format.
+    pub fn parse_strict(input: &str, format: EuiFormat) -> Result<Self, StringToEuiError> {
+        Eui64::parse_with(input, &ParseOptions::from(format))
+    }
+
+    /// Parses address accepting only canonical IEEE form (`4D-7E-54-00-00-97-2E-EF`).
+    pub fn parse_canonical(input: &str) -> Result<Self, StringToEuiError> {
+        Eui64::parse_strict(input, EuiFormat::CANONICAL)
+    }
+
+    /// Parses the same forms as `TryFrom<&str>` from raw bytes without UTF-8 validation.
+    pub fn parse_ascii(input: &[u8]) -> Result<Self, StringToEuiError> {
+        check_string_length(input.len(), &EUI64_STRING_LENGTHS)?;
+
+        let mut result = [0; 8];
+        ascii_to_eui(input, &mut result)?;
+
+        Ok(Eui(result))
+    }
+
+    /// Parses the same forms as `TryFrom<&str>` and reports which convention input used.
+    pub fn parse_detect(input: &str) -> Result<(Self, DetectedFormat), StringToEuiError> {
+        check_string_length(input.len(), &EUI64_STRING_LENGTHS)?;
+
+        let mut result = [0; 8];
+        string_to_eui(input, &mut result)?;
+
+        Ok((Eui(result), DetectedFormat::detect(input)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        Case, DetectedFormat, Eui48, Eui64, EuiFormat, ParseOptions, Separator, StringToEuiError,
+    };
+
+    #[test]
+    fn test_eui48_parse_ether_aton() {
+        assert_eq!(
+            Eui48::parse_ether_aton("0:a:b:c:d:e"),
+            Ok(Eui48::from(0x000A0B0C0D0E))
+        );
+        assert_eq!(
+            Eui48::parse_ether_aton("4d:7e:54:97:2e:ef"),
+            Ok(Eui48::from(85204980412143))
+        );
+        assert_eq!(
+            Eui48::parse_ether_aton("8:0:20:1:2:FF"),
+            Ok(Eui48::from(0x0800200102FF))
+        );
+    }
+
+    #[test]
+    fn test_eui64_parse_ether_aton() {
+        assert_eq!(
+            Eui64::parse_ether_aton("4d:7e:54:0:0:97:2e:ef"),
+            Ok(Eui64::from(5583992946972634863))
+        );
+    }
+
+    #[test]
+    fn test_eui48_parse_ether_aton_invalid() {
+        assert_eq!(
+            Eui48::parse_ether_aton(""),
+            Err(StringToEuiError::InvalidLength { length: 0 })
+        );
+        assert_eq!(
+            Eui48::parse_ether_aton("0:a:b:c:d"),
+            Err(StringToEuiError::TooFewOctets {
+                octets: 5,
+                expected: 6
+            })
+        );
+        assert_eq!(
+            Eui48::parse_ether_aton("0:a:b:c:d:e:f"),
+            Err(StringToEuiError::TrailingCharacters { position: 11 })
+        );
+        assert_eq!(
+            Eui48::parse_ether_aton("0:a::c:d:e"),
+            Err(StringToEuiError::InvalidSeparatorPlace { position: 4 })
+        );
+        assert_eq!(
+            Eui48::parse_ether_aton("0:abc:b:c:d:e"),
+            Err(StringToEuiError::InvalidSeparatorPlace { position: 4 })
+        );
+        assert_eq!(
+            Eui48::parse_ether_aton("0:a:g:c:d:e"),
+            Err(StringToEuiError::InvalidChar {
+                char: 'g',
+                position: 4
+            })
+        );
+    }
+
+    #[test]
+    fn test_eui48_parse_lenient() {
+        let eui48 = Eui48::from(85204980412143);
+
+        assert_eq!(Eui48::parse_lenient("4d:7e:54:97:2e:ef\n"), Ok(eui48));
+        assert_eq!(Eui48::parse_lenient("  4D-7e:54.97 2E-ef\r\n"), Ok(eui48));
+        assert_eq!(Eui48::parse_lenient("\t4d7e54972eef"), Ok(eui48));
+        assert_eq!(Eui48::parse_lenient("4d7e.5497.2eef"), Ok(eui48));
+    }
+
+    #[test]
+    fn test_eui64_parse_lenient() {
+        assert_eq!(
+            Eui64::parse_lenient("4D:7E:54:00-00:97:2E:EF\n"),
+            Ok(Eui64::from(5583992946972634863))
+        );
+    }
+
+    #[test]
+    fn test_eui48_parse_lenient_invalid() {
+        assert_eq!(
+            Eui48::parse_lenient("4d:7e:54:97:2e\n"),
+            Err(StringToEuiError::TooFewOctets {
+                octets: 5,
+                expected: 6
+            })
+        );
+        assert_eq!(
+            Eui48::parse_lenient("4d:7e:54:97:2e:ef:01"),
+            Err(StringToEuiError::TrailingCharacters { position: 18 })
+        );
+        assert_eq!(
+            Eui48::parse_lenient("4d7e54972ee"),
+            Err(StringToEuiError::OddDigitCount { digits: 11 })
+        );
+        assert_eq!(
+            Eui48::parse_lenient("4d:7e:54:97:2e:eg"),
+            Err(StringToEuiError::InvalidChar {
+                char: 'g',
+                position: 16
+            })
+        );
+    }
+
+    #[test]
+    fn test_eui48_parse_with_default_options() {
+        let eui48 = Eui48::from(85204980412143);
+        let options = ParseOptions::default();
+
+        assert_eq!(Eui48::parse_with("4d:7e:54:97:2e:ef", &options), Ok(eui48));
+        assert_eq!(Eui48::parse_with("4D-7E-54-97-2E-EF", &options), Ok(eui48));
+        assert_eq!(Eui48::parse_with("4d7e.5497.2eef", &options), Ok(eui48));
+        assert_eq!(Eui48::parse_with("4d7e:5497:2eef", &options), Ok(eui48));
+        assert_eq!(Eui48::parse_with("4D 7E 54 97 2E EF", &options), Ok(eui48));
+        assert_eq!(Eui48::parse_with("4d7e54972eef", &options), Ok(eui48));
+
+        assert_eq!(
+            Eui48::parse_with("4d7e54.972eef", &options),
+            Err(StringToEuiError::InvalidSeparatorPlace { position: 6 })
+        );
+        assert_eq!(
+            Eui48::parse_with("4d:7e:54:97:2eef", &options),
+            Err(StringToEuiError::GroupCountMismatch {
+                expected: 6,
+                actual: 5
+            })
+        );
+        assert_eq!(
+            Eui48::parse_with("4d:7e:54:97:2e:ef:01", &options),
+            Err(StringToEuiError::GroupCountMismatch {
+                expected: 6,
+                actual: 7
+            })
+        );
+        assert_eq!(
+            Eui48::parse_with("4d7e.5497", &options),
+            Err(StringToEuiError::GroupCountMismatch {
+                expected: 3,
+                actual: 2
+            })
+        );
+        assert_eq!(
+            Eui48::parse_with("4d:7e:54-97:2e:ef", &options),
+            Err(StringToEuiError::OnlyOneSeparatorTypeExpected)
+        );
+        assert_eq!(
+            Eui48::parse_with(" 4d:7e:54:97:2e:ef", &options),
+            Err(StringToEuiError::InvalidSeparatorPlace { position: 0 })
+        );
+    }
+
+    #[test]
+    fn test_eui48_parse_with_separators() {
+        let options = ParseOptions::new().separators(&[Separator::Colon]);
+
+        assert!(Eui48::parse_with("4d:7e:54:97:2e:ef", &options).is_ok());
+        assert_eq!(
+            Eui48::parse_with("4d-7e-54-97-2e-ef", &options),
+            Err(StringToEuiError::InvalidChar {
+                char: '-',
+                position: 2
+            })
+        );
+        assert_eq!(
+            Eui48::parse_with("4d7e54972eef", &options),
+            Err(StringToEuiError::InvalidSeparatorPlace { position: 12 })
+        );
+    }
+
+    #[test]
+    fn test_eui48_parse_with_group_sizes() {
+        let options = ParseOptions::new().group_sizes(&[3]);
+
+        assert_eq!(
+            Eui48::parse_with("4d7e54-972eef", &options),
+            Ok(Eui48::from(85204980412143))
+        );
+        assert_eq!(
+            Eui48::parse_with("4d-7e-54-97-2e-ef", &options),
+            Err(StringToEuiError::InvalidSeparatorPlace { position: 2 })
+        );
+    }
+
+    #[test]
+    fn test_eui48_parse_with_case() {
+        let options = ParseOptions::new().case(Case::Upper);
+
+        assert!(Eui48::parse_with("4D-7E-54-97-2E-EF", &options).is_ok());
+        assert_eq!(
+            Eui48::parse_with("4D-7E-54-97-2E-eF", &options),
+            Err(StringToEuiError::InvalidChar {
+                char: 'e',
+                position: 15
+            })
+        );
+    }
+
+    #[test]
+    fn test_eui64_parse_with_non_strict() {
+        let options = ParseOptions::new().strict(false);
+
+        assert_eq!(
+            Eui64::parse_with(" 4d:7e54-00:00.97:2eef\n", &options),
+            Ok(Eui64::from(5583992946972634863))
+        );
+        assert_eq!(
+            Eui64::parse_with("4d:7e:54:00:00:97:2e", &options),
+            Err(StringToEuiError::TooFewOctets {
+                octets: 7,
+                expected: 8
+            })
+        );
+    }
+
+    #[test]
+    fn test_eui48_parse_ascii() {
+        let eui48 = Eui48::from(85204980412143);
+
+        assert_eq!(Eui48::parse_ascii(b"4D-7E-54-97-2E-EF"), Ok(eui48));
+        assert_eq!(Eui48::parse_ascii(b"4d:7e:54:97:2e:ef"), Ok(eui48));
+        assert_eq!(Eui48::parse_ascii(b"4d7e.5497.2eef"), Ok(eui48));
+        assert_eq!(Eui48::parse_ascii(b"4d7e54972eef"), Ok(eui48));
+    }
+
+    #[test]
+    fn test_eui64_parse_ascii() {
+        assert_eq!(
+            Eui64::parse_ascii(b"4D-7E-54-00-00-97-2E-EF"),
+            Ok(Eui64::from(5583992946972634863))
+        );
+    }
+
+    #[test]
+    fn test_eui48_parse_ascii_invalid() {
+        assert_eq!(
+            Eui48::parse_ascii(b"4D-7E-54-97"),
+            Err(StringToEuiError::InvalidLength { length: 11 })
+        );
+        assert_eq!(
+            Eui48::parse_ascii(b"4D-7E-54-97-2E-E\xFF"),
+            Err(StringToEuiError::InvalidChar {
+                char: '\u{FF}',
+                position: 16
+            })
+        );
+        assert_eq!(
+            Eui48::parse_ascii(b"4D-7E-54:97-2E-EF"),
+            Err(StringToEuiError::OnlyOneSeparatorTypeExpected)
+        );
+    }
+
+    #[test]
+    fn test_eui48_parse_detect() {
+        let eui48 = Eui48::from(85204980412143);
+        let cases = [
+            ("4d:7e:54:97:2e:ef", DetectedFormat::ColonLower),
+            ("4D:7E:54:97:2E:EF", DetectedFormat::ColonUpper),
+            ("4d-7e-54-97-2e-ef", DetectedFormat::DashLower),
+            ("4D-7E-54-97-2E-EF", DetectedFormat::DashUpper),
+            ("4d 7e 54 97 2e ef", DetectedFormat::SpaceLower),
+            ("4d7e.5497.2eef", DetectedFormat::DottedLower),
+            ("4D7E.5497.2EEF", DetectedFormat::DottedUpper),
+            ("4d7e54972eef", DetectedFormat::BareLower),
+            ("4D7e54972EEF", DetectedFormat::BareUpper),
+        ];
+
+        for (input, format) in cases.iter() {
+            assert_eq!(Eui48::parse_detect(input), Ok((eui48, *format)));
+        }
+    }
+
+    #[test]
+    fn test_eui64_parse_detect() {
+        let (eui64, format) = Eui64::parse_detect("4d:7e:54:00:00:97:2e:ef").unwrap();
+
+        assert_eq!(eui64, Eui64::from(5583992946972634863));
+        assert_eq!(format, DetectedFormat::ColonLower);
+        assert_eq!(
+            Eui64::parse_detect("4d:7e:54:00:00:97:2e"),
+            Err(StringToEuiError::InvalidLength { length: 20 })
+        );
+    }
+
+    #[test]
+    fn test_detected_format_round_trip() {
+        for input in [
+            "4d:7e:54:97:2e:ef",
+            "4D 7E 54 97 2E EF",
+            "4d7e.5497.2eef",
+            "4D7E.5497.2EEF",
+        ]
+        .iter()
+        {
+            let (eui48, format) = Eui48::parse_detect(input).unwrap();
+
+            assert_eq!(eui48.format_with(format.to_format()), *input);
+        }
+        assert_eq!(DetectedFormat::DashUpper.to_format(), EuiFormat::CANONICAL);
+    }
+
+    #[test]
+    fn test_eui48_parse_canonical() {
+        assert_eq!(
+            Eui48::parse_canonical("4D-7E-54-97-2E-EF"),
+            Ok(Eui48::from(85204980412143))
+        );
+        assert_eq!(
+            Eui48::parse_canonical("4D-7E-54-97-2e-EF"),
+            Err(StringToEuiError::InvalidChar {
+                char: 'e',
+                position: 13
+            })
+        );
+        assert_eq!(
+            Eui48::parse_canonical("4D:7E:54:97:2E:EF"),
+            Err(StringToEuiError::InvalidChar {
+                char: ':',
+                position: 2
+            })
+        );
+        assert_eq!(
+            Eui48::parse_canonical("4D7E54972EEF"),
+            Err(StringToEuiError::InvalidSeparatorPlace { position: 12 })
+        );
+    }
+
+    #[test]
+    fn test_eui64_parse_canonical() {
+        assert_eq!(
+            Eui64::parse_canonical("4D-7E-54-00-00-97-2E-EF"),
+            Ok(Eui64::from(5583992946972634863))
+        );
+        assert!(Eui64::parse_canonical("4d-7e-54-00-00-97-2e-ef").is_err());
+    }
+
+    #[test]
+    fn test_eui48_parse_strict() {
+        let eui48 = Eui48::from(85204980412143);
+
+        assert_eq!(
+            Eui48::parse_strict("4d7e.5497.2eef", EuiFormat::DOTTED),
+            Ok(eui48)
+        );
+        assert_eq!(
+            Eui48::parse_strict("4d7e54972eef", EuiFormat::BARE_LOWER),
+            Ok(eui48)
+        );
+        assert_eq!(
+            Eui48::parse_strict("4d:7e:54:97:2e:ef", EuiFormat::DOTTED),
+            Err(StringToEuiError::InvalidChar {
+                char: ':',
+                position: 2
+            })
+        );
+        assert_eq!(
+            Eui48::parse_strict("4d7e54.972eef", EuiFormat::DOTTED),
+            Err(StringToEuiError::InvalidSeparatorPlace { position: 6 })
+        );
+    }
+}
@@ -0,0 +1,233 @@
+use crate::{Eui, Eui48, Eui48Mask, StringToEuiError};
+use core::fmt::{self, Display, Formatter, Write};
+
+/// Glob-style pattern like `4D:7E:54:*:*:*`, where `*` matches any octet and `?` any
+/// hexadecimal digit.
+///
+/// Octets may be separated by `:`, `-` or space or not separated at all, e.g. `4D7E5?***`.
+#[derive(Eq, PartialEq, Copy, Clone, Hash, Debug)]
+pub struct Eui48Pattern {
+    mask: Eui48Mask,
+}
+
+impl Eui48Pattern {
+    /// Parses glob-style pattern.
+    pub fn parse(input: &str) -> Result<Self, StringToEuiError> {
+        let mut pattern = [0u8; 6];
+        let mut mask = [0u8; 6];
+        let mut nibbles = 0usize;
+        let mut group_nibbles = 0usize;
+        let mut separator = None;
+
+        for (position, c) in input.char_indices() {
+            if matches!(c, ':' | '-' | ' ') {
+                if group_nibbles != 2 || (separator.is_none() && nibbles != 2) {
+                    return Err(StringToEuiError::InvalidSeparatorPlace { position });
+                }
+
+                match separator {
+                    Some(s) if s != c => {
+                        return Err(StringToEuiError::OnlyOneSeparatorTypeExpected)
+                    }
+                    _ => separator = Some(c),
+                }
+
+                group_nibbles = 0;
+                continue;
+            }
+
+            let width = match c {
+                '*' if nibbles % 2 == 0 => 2,
+                '?' => 1,
+                c if c.is_ascii_hexdigit() => 1,
+                _ => return Err(StringToEuiError::InvalidChar { char: c, position }),
+            };
+
+            if separator.is_some() && group_nibbles == 2 {
+                return Err(StringToEuiError::InvalidSeparatorPlace { position });
+            }
+
+            if nibbles + width > 12 {
+                return Err(StringToEuiError::TrailingCharacters { position });
+            }
+
+            if let Some(value) = c.to_digit(16) {
+                let shift = if nibbles % 2 == 0 { 4 } else { 0 };
+
+                pattern[nibbles / 2] |= (value as u8) << shift;
+                mask[nibbles / 2] |= 0x0F << shift;
+            }
+
+            nibbles += width;
+            group_nibbles += width;
+        }
+
+        if separator.is_some() && group_nibbles != 2 {
+            return Err(StringToEuiError::InvalidSeparatorPlace {
+                position: input.len(),
+            });
+        }
+
+        if nibbles % 2 != 0 {
+            return Err(StringToEuiError::OddDigitCount { digits: nibbles });
+        }
+
+        if nibbles < 12 {
+            return Err(StringToEuiError::TooFewOctets {
+                octets: nibbles / 2,
+                expected: 6,
+            });
+        }
+
+        Ok(Eui48Pattern {
+            mask: Eui48Mask::new(Eui(pattern), Eui(mask)),
+        })
+    }
+
+    /// Returns `true` if eui matches pattern.
+    #[inline]
+    pub fn matches(&self, eui: &Eui48) -> bool {
+        self.mask.matches(eui)
+    }
+
+    /// Creates pattern from mask covering whole hexadecimal digits, `None` otherwise.
+    pub fn from_mask(mask: Eui48Mask) -> Option<Self> {
+        let whole_digits = mask.mask().0.iter().all(|&octet| {
+            matches!(octet & 0xF0, 0x00 | 0xF0) && matches!(octet & 0x0F, 0x00 | 0x0F)
+        });
+
+        if whole_digits {
+            Some(Eui48Pattern { mask })
+        } else {
+            None
+        }
+    }
+
+    /// Returns equivalent bit mask.
+    #[inline]
+    pub fn to_mask(&self) -> Eui48Mask {
+        self.mask
+    }
+}
+
+impl From<Eui48Pattern> for Eui48Mask {
+    fn from(pattern: Eui48Pattern) -> Self {
+        pattern.mask
+    }
+}
+
+/// Prints uppercase colon separated form, e.g. `4D:7E:5?:*:*:*`.
+impl Display for Eui48Pattern {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let pattern = self.mask.pattern().0;
+        let mask = self.mask.mask().0;
+
+        for (i, (&octet, &mask)) in pattern.iter().zip(mask.iter()).enumerate() {
+            if i != 0 {
+                f.write_char(':')?;
+            }
+
+            if mask == 0 {
+                f.write_char('*')?;
+                continue;
+            }
+
+            for shift in [4, 0] {
+                if (mask >> shift) & 0x0F == 0 {
+                    f.write_char('?')?;
+                } else {
+                    write!(f, "{:X}", (octet >> shift) & 0x0F)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use crate::{Eui48, Eui48Mask, Eui48Pattern, StringToEuiError};
+    use std::format;
+
+    #[test]
+    fn test_pattern_matches() {
+        let vendor = Eui48Pattern::parse("4D:7E:54:*:*:*").unwrap();
+
+        assert!(vendor.matches(&Eui48::from(85204980412143)));
+        assert!(!vendor.matches(&Eui48::from(0x4D7E_5597_2EEF)));
+        assert_eq!(
+            vendor.to_mask(),
+            Eui48Mask::from_prefix(Eui48::from(0x4D7E_5400_0000), 24).unwrap()
+        );
+
+        let nibble = Eui48Pattern::parse("4d7e5?***").unwrap();
+
+        assert!(nibble.matches(&Eui48::from(0x4D7E_5F00_0000)));
+        assert!(!nibble.matches(&Eui48::from(0x4D7E_6400_0000)));
+        assert!(Eui48Pattern::parse("?1-*-*-*-*-*")
+            .unwrap()
+            .matches(&Eui48::from(0xF100_0000_0000)));
+    }
+
+    #[test]
+    fn test_pattern_parse_errors() {
+        assert_eq!(
+            Eui48Pattern::parse("4D:7E:54:*:*"),
+            Err(StringToEuiError::TooFewOctets {
+                octets: 5,
+                expected: 6
+            })
+        );
+        assert_eq!(
+            Eui48Pattern::parse("4D:7E:54:*:*:*:*"),
+            Err(StringToEuiError::TrailingCharacters { position: 15 })
+        );
+        assert_eq!(
+            Eui48Pattern::parse("4D:7E:5*:*:*:*"),
+            Err(StringToEuiError::InvalidChar {
+                char: '*',
+                position: 7
+            })
+        );
+        assert_eq!(
+            Eui48Pattern::parse("4D:7E-54:*:*:*"),
+            Err(StringToEuiError::OnlyOneSeparatorTypeExpected)
+        );
+        assert_eq!(
+            Eui48Pattern::parse("4D:7E:54:*:*:*?"),
+            Err(StringToEuiError::InvalidSeparatorPlace { position: 14 })
+        );
+        assert_eq!(
+            Eui48Pattern::parse("4D7E:54:*:*:*"),
+            Err(StringToEuiError::InvalidSeparatorPlace { position: 4 })
+        );
+        assert_eq!(
+            Eui48Pattern::parse("4D:7E:54:*:*:g"),
+            Err(StringToEuiError::InvalidChar {
+                char: 'g',
+                position: 13
+            })
+        );
+    }
+
+    #[test]
+    fn test_pattern_display() {
+        let pattern = Eui48Pattern::parse("4d-7e-5?-*-*-?f").unwrap();
+
+        assert_eq!(format!("{}", pattern), "4D:7E:5?:*:*:?F");
+    }
+
+    #[test]
+    fn test_pattern_from_mask() {
+        let pattern = Eui48Pattern::parse("4D:7E:5?:*:*:*").unwrap();
+
+        assert_eq!(Eui48Pattern::from_mask(pattern.to_mask()), Some(pattern));
+        assert_eq!(
+            Eui48Pattern::from_mask(Eui48Mask::from_prefix(Eui48::from(0), 23).unwrap()),
+            None
+        );
+    }
+}
@@ -0,0 +1,174 @@
+use crate::{Eui48, Eui48Block};
+
+/// Allocator handing out euis of a block, tracking them in bitmap of `BYTES` octets, so up
+/// to `BYTES * 8` euis can be managed.
+///
+/// Bitmap can be exported and imported to persist allocations, bit `i % 8` of octet `i / 8`
+/// is set when `i`-th eui of block is allocated.
+///
+/// # Example
+///
+/// ```rust
+/// use eui::{Eui48, Eui48Block, EuiPool};
+///
+/// let block = Eui48Block::new(Eui48::from(0x0200_0000_0000), 16).unwrap();
+/// let mut pool: EuiPool<2> = EuiPool::new(block).unwrap();
+///
+/// let eui48 = pool.allocate().unwrap();
+/// assert_eq!(eui48, Eui48::from(0x0200_0000_0000));
+/// assert!(pool.release(&eui48));
+/// ```
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub struct EuiPool<const BYTES: usize> {
+    block: Eui48Block,
+    bitmap: [u8; BYTES],
+}
+
+impl<const BYTES: usize> EuiPool<BYTES> {
+    /// Creates pool with every eui of block free, `None` if block doesn't fit in bitmap.
+    pub fn new(block: Eui48Block) -> Option<Self> {
+        Self::import(block, [0; BYTES])
+    }
+
+    /// Creates pool from previously exported bitmap, `None` if block doesn't fit in bitmap
+    /// or bits past the end of block are set.
+    pub fn import(block: Eui48Block, bitmap: [u8; BYTES]) -> Option<Self> {
+        if block.len() > BYTES as u64 * 8 {
+            return None;
+        }
+
+        let pool = EuiPool { block, bitmap };
+        let len = block.len() as usize;
+
+        if (len..BYTES * 8).any(|index| pool.bit(index)) {
+            return None;
+        }
+
+        Some(pool)
+    }
+
+    /// Returns bitmap for persisting allocations.
+    #[inline]
+    pub fn export(&self) -> [u8; BYTES] {
+        self.bitmap
+    }
+
+    /// Returns block managed by pool.
+    #[inline]
+    pub fn block(&self) -> Eui48Block {
+        self.block
+    }
+
+    /// Returns number of allocated euis.
+    pub fn allocated(&self) -> u64 {
+        self.bitmap
+            .iter()
+            .map(|octet| octet.count_ones() as u64)
+            .sum()
+    }
+
+    fn bit(&self, index: usize) -> bool {
+        self.bitmap[index / 8] & (1 << (index % 8)) != 0
+    }
+
+    fn set_bit(&mut self, index: usize, value: bool) {
+        if value {
+            self.bitmap[index / 8] |= 1 << (index % 8);
+        } else {
+            self.bitmap[index / 8] &= !(1 << (index % 8));
+        }
+    }
+
+    fn index(&self, eui: &Eui48) -> Option<usize> {
+        if self.block.contains(eui) {
+            Some(eui.distance(&self.block.base()) as usize)
+        } else {
+            None
+        }
+    }
+
+    /// Allocates the lowest free eui, `None` when pool is exhausted.
+    pub fn allocate(&mut self) -> Option<Eui48> {
+        let len = self.block.len() as usize;
+        let (position, octet) = self
+            .bitmap
+            .iter()
+            .enumerate()
+            .find(|(_, &octet)| octet != 0xFF)?;
+
+        let index = position * 8 + octet.trailing_ones() as usize;
+
+        if index >= len {
+            return None;
+        }
+
+        self.set_bit(index, true);
+
+        Some(self.block.base() + index as u64)
+    }
+
+    /// Returns `true` if eui belongs to pool and is allocated.
+    pub fn is_allocated(&self, eui: &Eui48) -> bool {
+        self.index(eui).is_some_and(|index| self.bit(index))
+    }
+
+    /// Returns eui to pool, `false` if it wasn't allocated from this pool.
+    pub fn release(&mut self, eui: &Eui48) -> bool {
+        match self.index(eui) {
+            Some(index) if self.bit(index) => {
+                self.set_bit(index, false);
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Eui48, Eui48Block, EuiPool};
+
+    #[test]
+    fn test_pool_allocate() {
+        let block = Eui48Block::new(Eui48::from(0x0200_0000_0000), 10).unwrap();
+        let mut pool: EuiPool<2> = EuiPool::new(block).unwrap();
+
+        for offset in 0..10 {
+            assert_eq!(
+                pool.allocate(),
+                Some(Eui48::from(0x0200_0000_0000 + offset))
+            );
+        }
+
+        assert_eq!(pool.allocate(), None);
+        assert_eq!(pool.allocated(), 10);
+
+        assert!(pool.release(&Eui48::from(0x0200_0000_0003)));
+        assert!(!pool.release(&Eui48::from(0x0200_0000_0003)));
+        assert!(!pool.release(&Eui48::from(0x0200_0000_000A)));
+        assert!(!pool.is_allocated(&Eui48::from(0x0200_0000_0003)));
+        assert_eq!(pool.allocate(), Some(Eui48::from(0x0200_0000_0003)));
+    }
+
+    #[test]
+    fn test_pool_import_export() {
+        let block = Eui48Block::new(Eui48::from(0x0200_0000_0000), 10).unwrap();
+        let mut pool: EuiPool<2> = EuiPool::new(block).unwrap();
+
+        pool.allocate();
+        pool.allocate();
+
+        let bitmap = pool.export();
+        assert_eq!(bitmap, [0b11, 0]);
+
+        let mut restored: EuiPool<2> = EuiPool::import(block, bitmap).unwrap();
+        assert!(restored.is_allocated(&Eui48::from(0x0200_0000_0001)));
+        assert_eq!(restored.allocate(), Some(Eui48::from(0x0200_0000_0002)));
+
+        assert_eq!(EuiPool::<2>::import(block, [0, 0b100]), None);
+        assert_eq!(
+            EuiPool::<1>::new(Eui48Block::new(Eui48::from(0), 9).unwrap()),
+            None
+        );
+    }
+}
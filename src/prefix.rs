@@ -0,0 +1,206 @@
+use crate::Eui48;
+
+struct Entry<V> {
+    prefix: u64,
+    length: u8,
+    value: V,
+}
+
+/// Fixed-capacity table mapping `Eui48` prefixes of any bit length to values with longest
+/// prefix lookup, e.g. for classifying traffic by vendor or assignment block.
+///
+/// Entries are kept sorted from the longest prefix, so lookup returns the first match.
+///
+/// # Example
+///
+/// ```rust
+/// use eui::{Eui48, PrefixTable};
+///
+/// let mut table: PrefixTable<&str, 4> = PrefixTable::new();
+/// table.insert(Eui48::from(0x4D7E_5400_0000), 24, "vendor").unwrap();
+/// table.insert(Eui48::from(0x4D7E_5497_0000), 32, "batch").unwrap();
+///
+/// assert_eq!(table.lookup(&Eui48::from(85204980412143)), Some(&"batch"));
+/// assert_eq!(table.lookup(&Eui48::from(0x4D7E_5400_0001)), Some(&"vendor"));
+/// assert_eq!(table.lookup(&Eui48::from(1)), None);
+/// ```
+pub struct PrefixTable<V, const N: usize> {
+    entries: [Option<Entry<V>>; N],
+    len: usize,
+}
+
+fn prefix_bits(eui: &Eui48, length: u8) -> u64 {
+    let mask = 0xFFFF_FFFF_FFFFu64
+        .checked_shl(48 - length as u32)
+        .unwrap_or(0)
+        & 0xFFFF_FFFF_FFFF;
+
+    u64::from(*eui) & mask
+}
+
+impl<V, const N: usize> PrefixTable<V, N> {
+    /// Creates empty table.
+    pub fn new() -> Self {
+        PrefixTable {
+            entries: core::array::from_fn(|_| None),
+            len: 0,
+        }
+    }
+
+    /// Returns number of prefixes in table.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if table has no prefixes.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    fn entries(&self) -> impl Iterator<Item = &Entry<V>> {
+        self.entries[..self.len].iter().flatten()
+    }
+
+    fn position(&self, prefix: u64, length: u8) -> Option<usize> {
+        self.entries()
+            .position(|entry| entry.length == length && entry.prefix == prefix)
+    }
+
+    /// Maps first `length` bits of `prefix` to value, returning previous value of the same
+    /// prefix.
+    ///
+    /// Value is given back as error when table is full or `length` exceeds 48 bits.
+    pub fn insert(&mut self, prefix: Eui48, length: u8, value: V) -> Result<Option<V>, V> {
+        if length > 48 {
+            return Err(value);
+        }
+
+        let prefix = prefix_bits(&prefix, length);
+
+        if let Some(index) = self.position(prefix, length) {
+            let entry = self.entries[index].as_mut().map(|entry| &mut entry.value);
+            return Ok(entry.map(|old| core::mem::replace(old, value)));
+        }
+
+        if self.len == N {
+            return Err(value);
+        }
+
+        let index = self
+            .entries()
+            .position(|entry| entry.length < length)
+            .unwrap_or(self.len);
+
+        self.entries[index..=self.len].rotate_right(1);
+        self.entries[index] = Some(Entry {
+            prefix,
+            length,
+            value,
+        });
+        self.len += 1;
+
+        Ok(None)
+    }
+
+    /// Removes prefix, returning its value.
+    pub fn remove(&mut self, prefix: Eui48, length: u8) -> Option<V> {
+        if length > 48 {
+            return None;
+        }
+
+        let index = self.position(prefix_bits(&prefix, length), length)?;
+        let entry = self.entries[index].take();
+
+        self.entries[index..self.len].rotate_left(1);
+        self.len -= 1;
+
+        entry.map(|entry| entry.value)
+    }
+
+    /// Returns value of the exact prefix.
+    pub fn get(&self, prefix: Eui48, length: u8) -> Option<&V> {
+        if length > 48 {
+            return None;
+        }
+
+        let prefix = prefix_bits(&prefix, length);
+
+        self.entries()
+            .find(|entry| entry.length == length && entry.prefix == prefix)
+            .map(|entry| &entry.value)
+    }
+
+    /// Returns value of the longest prefix matching eui.
+    pub fn lookup(&self, eui: &Eui48) -> Option<&V> {
+        self.longest_match(eui).map(|(_, value)| value)
+    }
+
+    /// Returns length and value of the longest prefix matching eui.
+    pub fn longest_match(&self, eui: &Eui48) -> Option<(u8, &V)> {
+        self.entries()
+            .find(|entry| prefix_bits(eui, entry.length) == entry.prefix)
+            .map(|entry| (entry.length, &entry.value))
+    }
+}
+
+impl<V, const N: usize> Default for PrefixTable<V, N> {
+    fn default() -> Self {
+        PrefixTable::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Eui48, PrefixTable};
+
+    #[test]
+    fn test_prefix_table_longest_match() {
+        let mut table: PrefixTable<u32, 4> = PrefixTable::new();
+
+        assert_eq!(table.insert(Eui48::from(0), 0, 0), Ok(None));
+        assert_eq!(
+            table.insert(Eui48::from(0x4D7E_5400_0000), 24, 24),
+            Ok(None)
+        );
+        assert_eq!(
+            table.insert(Eui48::from(0x4D7E_5497_2EEF), 36, 36),
+            Ok(None)
+        );
+        assert_eq!(
+            table.insert(Eui48::from(0x4D7E_5497_2EEF), 48, 48),
+            Ok(None)
+        );
+
+        assert_eq!(
+            table.longest_match(&Eui48::from(85204980412143)),
+            Some((48, &48))
+        );
+        assert_eq!(table.lookup(&Eui48::from(0x4D7E_5497_2000)), Some(&36));
+        assert_eq!(table.lookup(&Eui48::from(0x4D7E_5400_0000)), Some(&24));
+        assert_eq!(table.lookup(&Eui48::from(0x4D7E_5500_0000)), Some(&0));
+        assert_eq!(table.len(), 4);
+    }
+
+    #[test]
+    fn test_prefix_table_insert_remove() {
+        let mut table: PrefixTable<u32, 2> = PrefixTable::new();
+
+        assert_eq!(table.insert(Eui48::from(0x4D7E_5400_0000), 24, 1), Ok(None));
+        assert_eq!(
+            table.insert(Eui48::from(0x4D7E_54FF_FFFF), 24, 2),
+            Ok(Some(1))
+        );
+        assert_eq!(table.insert(Eui48::from(0x0100_5E00_0000), 25, 3), Ok(None));
+        assert_eq!(table.insert(Eui48::from(0x0200_0000_0000), 8, 4), Err(4));
+        assert_eq!(table.insert(Eui48::from(0), 49, 5), Err(5));
+
+        assert_eq!(table.get(Eui48::from(0x4D7E_5400_0000), 24), Some(&2));
+        assert_eq!(table.remove(Eui48::from(0x0100_5E00_0000), 25), Some(3));
+        assert_eq!(table.remove(Eui48::from(0x0100_5E00_0000), 25), None);
+        assert_eq!(table.lookup(&Eui48::from(0x0100_5E00_0001)), None);
+        assert_eq!(table.lookup(&Eui48::from(85204980412143)), Some(&2));
+        assert_eq!(table.len(), 1);
+    }
+}
@@ -0,0 +1,151 @@
+use crate::{Eui, Eui48, LOCAL_BIT, MULTICAST_BIT};
+use core::ops::RangeInclusive;
+
+/// Pseudorandom function supplied by the caller, e.g. SipHash or truncated HMAC-SHA256.
+///
+/// Data is fed with `update` and the first 64 bits of the result are returned by `finish`.
+pub trait Prf {
+    fn update(&mut self, data: &[u8]);
+    fn finish(self) -> u64;
+}
+
+/// Interface identifiers reserved by RFC 5453: subnet-router anycast, reserved subnet
+/// anycast and identifiers derived from IANA ethernet block.
+const RESERVED_IIDS: [RangeInclusive<u64>; 3] = [
+    0..=0,
+    0xFDFF_FFFF_FFFF_FF80..=0xFDFF_FFFF_FFFF_FFFF,
+    0x0200_5EFF_FE00_0000..=0x0200_5EFF_FEFF_FFFF,
+];
+
+impl Eui48 {
+    /// Generates stable, semantically opaque IPv6 interface identifier as described in RFC 7217.
+    ///
+    /// The identifier is `F(prefix | eui48 | network_id | dad_counter | secret_key)`, `None`
+    /// when it is reserved by RFC 5453. Caller keeps `dad_counter`, incrementing it and
+    /// generating identifier again when `None` is returned or duplicate address detection fails.
+    pub fn stable_opaque_iid<P: Prf>(
+        &self,
+        mut prf: P,
+        prefix: &[u8; 8],
+        network_id: &[u8],
+        dad_counter: u8,
+        secret_key: &[u8],
+    ) -> Option<[u8; 8]> {
+        prf.update(prefix);
+        prf.update(&self.0);
+        prf.update(network_id);
+        prf.update(&[dad_counter]);
+        prf.update(secret_key);
+
+        let iid = prf.finish();
+
+        if RESERVED_IIDS.iter().any(|reserved| reserved.contains(&iid)) {
+            None
+        } else {
+            Some(iid.to_be_bytes())
+        }
+    }
+
+    /// Replaces eui with stable but unlinkable pseudonym `F(key | eui48)`.
+    ///
+    /// When `preserve_flags` is set, multicast and locally administered bits are copied from
+    /// the original address so pseudonyms keep their kind.
+    pub fn pseudonymize<P: Prf>(&self, mut prf: P, key: &[u8], preserve_flags: bool) -> Eui48 {
+        prf.update(key);
+        prf.update(&self.0);
+
+        let mut octets = [0; 6];
+        octets.copy_from_slice(&prf.finish().to_be_bytes()[2..]);
+
+        if preserve_flags {
+            let flags = MULTICAST_BIT | LOCAL_BIT;
+            octets[0] = (octets[0] & !flags) | (self.0[0] & flags);
+        }
+
+        Eui(octets)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::fnv::Fnv;
+    use crate::{Eui48, Prf};
+
+    #[test]
+    fn test_eui48_stable_opaque_iid() {
+        let eui48 = Eui48::from(85204980412143);
+        let prefix = [0x20, 0x01, 0x0d, 0xb8, 0, 0, 0, 0];
+
+        let iid = eui48.stable_opaque_iid(Fnv::default(), &prefix, b"ssid", 0, b"secret");
+
+        let mut expected = Fnv::default();
+        expected.update(&prefix);
+        expected.update(&[0x4D, 0x7E, 0x54, 0x97, 0x2E, 0xEF]);
+        expected.update(b"ssid");
+        expected.update(&[0]);
+        expected.update(b"secret");
+
+        assert_eq!(iid, Some(expected.finish().to_be_bytes()));
+        assert_eq!(
+            iid,
+            eui48.stable_opaque_iid(Fnv::default(), &prefix, b"ssid", 0, b"secret")
+        );
+        assert_ne!(
+            iid,
+            eui48.stable_opaque_iid(Fnv::default(), &prefix, b"ssid", 1, b"secret")
+        );
+        assert_ne!(
+            iid,
+            eui48.stable_opaque_iid(Fnv::default(), &prefix, b"ssid", 0, b"other")
+        );
+    }
+
+    #[test]
+    fn test_eui48_stable_opaque_iid_reserved() {
+        struct Fixed(u64);
+
+        impl Prf for Fixed {
+            fn update(&mut self, _: &[u8]) {}
+
+            fn finish(self) -> u64 {
+                self.0
+            }
+        }
+
+        let eui48 = Eui48::from(85204980412143);
+        let iid = |value| eui48.stable_opaque_iid(Fixed(value), &[0; 8], b"", 0, b"");
+
+        assert_eq!(iid(0), None);
+        assert_eq!(iid(0xFDFF_FFFF_FFFF_FF80), None);
+        assert_eq!(iid(0xFDFF_FFFF_FFFF_FFFF), None);
+        assert!(iid(u64::MAX).is_some());
+        assert_eq!(iid(0x0200_5EFF_FE00_5213), None);
+        assert_eq!(iid(1), Some([0, 0, 0, 0, 0, 0, 0, 1]));
+        assert_eq!(
+            iid(0xFDFF_FFFF_FFFF_FF7F),
+            Some([0xFD, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x7F])
+        );
+        assert!(iid(0x0200_5EFF_FF00_0000).is_some());
+    }
+
+    #[test]
+    fn test_eui48_pseudonymize() {
+        let eui48 = Eui48::from(85204980412143);
+        let pseudonym = eui48.pseudonymize(Fnv::default(), b"key", false);
+
+        let mut expected = Fnv::default();
+        expected.update(b"key");
+        expected.update(&[0x4D, 0x7E, 0x54, 0x97, 0x2E, 0xEF]);
+
+        assert_eq!(u64::from(pseudonym), expected.finish() & 0xFFFF_FFFF_FFFF);
+        assert_eq!(pseudonym, eui48.pseudonymize(Fnv::default(), b"key", false));
+        assert_ne!(
+            pseudonym,
+            eui48.pseudonymize(Fnv::default(), b"other", false)
+        );
+
+        let preserved = eui48.pseudonymize(Fnv::default(), b"key", true);
+        assert_eq!(preserved.0[0] & 0x03, 0x01);
+        assert_eq!(preserved.0[1..], pseudonym.0[1..]);
+    }
+}
@@ -0,0 +1,52 @@
+use crate::{Eui, Eui48, Eui64, Oui};
+use ::rand::distributions::{Distribution, Standard};
+use ::rand::Rng;
+
+/// Samples every octet uniformly, including multicast and locally administered addresses.
+impl Distribution<Eui48> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Eui48 {
+        let mut octets = [0; 6];
+        rng.fill_bytes(&mut octets);
+
+        Eui(octets)
+    }
+}
+
+/// Samples every octet uniformly, including multicast and locally administered addresses.
+impl Distribution<Eui64> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Eui64 {
+        let mut octets = [0; 8];
+        rng.fill_bytes(&mut octets);
+
+        Eui(octets)
+    }
+}
+
+impl Distribution<Oui> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Oui {
+        let mut octets = [0; 3];
+        rng.fill_bytes(&mut octets);
+
+        Oui(octets)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Eui48, Eui64, Oui};
+    use ::rand::rngs::mock::StepRng;
+    use ::rand::Rng;
+
+    #[test]
+    fn test_standard_distribution() {
+        let mut rng = StepRng::new(0x0102_0304_0506_0708, 0);
+
+        let eui48: Eui48 = rng.gen();
+        let eui64: Eui64 = rng.gen();
+        let oui: Oui = rng.gen();
+
+        assert_eq!(eui48, Eui48::from(0x0807_0605_0403));
+        assert_eq!(eui64, Eui64::from(0x0807_0605_0403_0201));
+        assert_eq!(oui, Oui::new([0x08, 0x07, 0x06]));
+    }
+}
@@ -0,0 +1,159 @@
+use crate::{Eui, Eui48, Oui, SlapQuadrant, LOCAL_BIT, MULTICAST_BIT};
+use rand_core::RngCore;
+
+impl Eui48 {
+    /// Generates random locally administered unicast address.
+    pub fn random_local<R: RngCore>(rng: &mut R) -> Self {
+        let mut octets = [0; 6];
+        rng.fill_bytes(&mut octets);
+
+        octets[0] = (octets[0] | LOCAL_BIT) & !MULTICAST_BIT;
+
+        Eui(octets)
+    }
+
+    /// Generates address with given vendor prefix and random NIC specific octets.
+    pub fn random_in_oui<R: RngCore>(oui: Oui, rng: &mut R) -> Self {
+        let mut octets = [0; 6];
+        octets[..3].copy_from_slice(&oui.0);
+        rng.fill_bytes(&mut octets[3..]);
+
+        Eui(octets)
+    }
+
+    /// Replaces NIC specific octets with random data, keeping vendor prefix, as done by some
+    /// Wi-Fi stacks for probe requests. `None` for multicast address.
+    pub fn randomize_nic<R: RngCore>(&self, rng: &mut R) -> Option<Self> {
+        if self.0[0] & MULTICAST_BIT != 0 {
+            return None;
+        }
+
+        Some(Eui48::random_in_oui(self.oui(), rng))
+    }
+
+    /// Generates random locally administered unicast address in requested SLAP quadrant,
+    /// `None` for ELI quadrant whose addresses must carry assignee's CID, see `random_eli`.
+    pub fn random_slap<R: RngCore>(quadrant: SlapQuadrant, rng: &mut R) -> Option<Self> {
+        if quadrant == SlapQuadrant::Eli {
+            return None;
+        }
+
+        let mut octets = [0; 6];
+        rng.fill_bytes(&mut octets);
+
+        octets[0] = (octets[0] & 0xF0) | quadrant.nibble();
+
+        Some(Eui(octets))
+    }
+
+    /// Generates Extended Local Identifier with given Company ID and random remaining octets,
+    /// `None` if CID is not in ELI quadrant.
+    pub fn random_eli<R: RngCore>(cid: Oui, rng: &mut R) -> Option<Self> {
+        if cid.0[0] & 0x0F != SlapQuadrant::Eli.nibble() {
+            return None;
+        }
+
+        Some(Eui48::random_in_oui(cid, rng))
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use crate::{Eui48, Oui, SlapQuadrant};
+    use rand_core::{impls, Error, RngCore};
+
+    /// Returns bytes of a counter, good enough to check bit handling.
+    pub(crate) struct StepRng(pub(crate) u64);
+
+    impl RngCore for StepRng {
+        fn next_u32(&mut self) -> u32 {
+            self.next_u64() as u32
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+            self.0
+        }
+
+        fn fill_bytes(&mut self, dest: &mut [u8]) {
+            impls::fill_bytes_via_next(self, dest)
+        }
+
+        fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+            self.fill_bytes(dest);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_random_local() {
+        let mut rng = StepRng(0);
+
+        for _ in 0..64 {
+            let octets = Eui48::random_local(&mut rng).0;
+
+            assert_eq!(octets[0] & 0x02, 0x02);
+            assert_eq!(octets[0] & 0x01, 0);
+        }
+
+        assert_ne!(Eui48::random_local(&mut rng), Eui48::random_local(&mut rng));
+    }
+
+    #[test]
+    fn test_random_in_oui() {
+        let mut rng = StepRng(0);
+        let oui = Oui::new([0x4d, 0x7e, 0x54]);
+
+        let first = Eui48::random_in_oui(oui, &mut rng);
+        let second = Eui48::random_in_oui(oui, &mut rng);
+
+        assert_eq!(first.oui(), oui);
+        assert_eq!(second.oui(), oui);
+        assert_ne!(first, second);
+    }
+
+    #[test]
+    fn test_random_slap() {
+        let mut rng = StepRng(0);
+
+        for quadrant in [SlapQuadrant::Sai, SlapQuadrant::Aai, SlapQuadrant::Reserved] {
+            for _ in 0..16 {
+                let eui48 = Eui48::random_slap(quadrant, &mut rng).unwrap();
+                assert_eq!(eui48.slap_quadrant(), Some(quadrant));
+            }
+        }
+
+        assert_eq!(Eui48::random_slap(SlapQuadrant::Eli, &mut rng), None);
+    }
+
+    #[test]
+    fn test_random_eli() {
+        let mut rng = StepRng(0);
+        let cid = Oui::new([0x1a, 0x2b, 0x3c]);
+
+        for _ in 0..16 {
+            let eui48 = Eui48::random_eli(cid, &mut rng).unwrap();
+
+            assert_eq!(eui48.oui(), cid);
+            assert_eq!(eui48.slap_quadrant(), Some(SlapQuadrant::Eli));
+        }
+
+        assert_eq!(
+            Eui48::random_eli(Oui::new([0x4d, 0x7e, 0x54]), &mut rng),
+            None
+        );
+    }
+
+    #[test]
+    fn test_randomize_nic() {
+        let mut rng = StepRng(0);
+        let eui48 = Eui48::from(0x4C7E_5497_2EEF);
+
+        let randomized = eui48.randomize_nic(&mut rng).unwrap();
+
+        assert_eq!(randomized.oui(), eui48.oui());
+        assert_ne!(randomized, eui48);
+        assert_ne!(Some(randomized), eui48.randomize_nic(&mut rng));
+        assert_eq!(Eui48::from(0x0100_5E00_0001).randomize_nic(&mut rng), None);
+    }
+}
@@ -0,0 +1,189 @@
+use crate::{Eui48, Eui64};
+use core::convert::TryFrom;
+use core::iter::FusedIterator;
+
+macro_rules! range {
+    ($name: ident, $eui: ident, $len: ty, $description: expr) => {
+        #[doc = $description]
+        #[derive(Eq, PartialEq, Clone, Debug)]
+        pub struct $name {
+            front: u64,
+            back: u64,
+            exhausted: bool,
+        }
+
+        impl $name {
+            /// Creates range from `start` to `end` inclusive, empty when `start > end`.
+            pub fn new(start: $eui, end: $eui) -> Self {
+                let front = u64::from(start);
+                let back = u64::from(end);
+
+                $name {
+                    front,
+                    back,
+                    exhausted: front > back,
+                }
+            }
+
+            /// Returns `true` if eui is within remaining part of range.
+            pub fn contains(&self, eui: &$eui) -> bool {
+                let value = u64::from(*eui);
+
+                !self.exhausted && self.front <= value && value <= self.back
+            }
+
+            /// Returns number of remaining euis.
+            pub fn len(&self) -> $len {
+                if self.exhausted {
+                    0
+                } else {
+                    <$len>::from(self.back - self.front) + 1
+                }
+            }
+
+            /// Returns `true` if no euis remain.
+            #[inline]
+            pub fn is_empty(&self) -> bool {
+                self.exhausted
+            }
+        }
+
+        impl $eui {
+            /// Returns iterator over euis from `start` to `end` inclusive.
+            #[inline]
+            pub fn range(start: $eui, end: $eui) -> $name {
+                $name::new(start, end)
+            }
+        }
+
+        /// Creates range without euis.
+        impl Default for $name {
+            fn default() -> Self {
+                $name {
+                    front: 0,
+                    back: 0,
+                    exhausted: true,
+                }
+            }
+        }
+
+        impl Iterator for $name {
+            type Item = $eui;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                if self.exhausted {
+                    return None;
+                }
+
+                let value = self.front;
+
+                if self.front == self.back {
+                    self.exhausted = true;
+                } else {
+                    self.front += 1;
+                }
+
+                Some($eui::from(value))
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                if self.exhausted {
+                    return (0, Some(0));
+                }
+
+                match (self.back - self.front)
+                    .checked_add(1)
+                    .and_then(|len| usize::try_from(len).ok())
+                {
+                    Some(len) => (len, Some(len)),
+                    None => (usize::MAX, None),
+                }
+            }
+        }
+
+        impl DoubleEndedIterator for $name {
+            fn next_back(&mut self) -> Option<Self::Item> {
+                if self.exhausted {
+                    return None;
+                }
+
+                let value = self.back;
+
+                if self.front == self.back {
+                    self.exhausted = true;
+                } else {
+                    self.back -= 1;
+                }
+
+                Some($eui::from(value))
+            }
+        }
+
+        impl FusedIterator for $name {}
+    };
+}
+
+range!(
+    Eui48Range,
+    Eui48,
+    u64,
+    "Inclusive range of `Eui48` iterated in ascending order."
+);
+range!(
+    Eui64Range,
+    Eui64,
+    u128,
+    "Inclusive range of `Eui64` iterated in ascending order."
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::{Eui48, Eui64};
+
+    #[test]
+    fn test_eui48_range() {
+        let mut range = Eui48::range(Eui48::from(0x4D7E_5497_2EFE), Eui48::from(0x4D7E_5497_2F01));
+
+        assert_eq!(range.len(), 4);
+        assert!(range.contains(&Eui48::from(0x4D7E_5497_2F00)));
+        assert_eq!(range.next(), Some(Eui48::from(0x4D7E_5497_2EFE)));
+        assert_eq!(range.next_back(), Some(Eui48::from(0x4D7E_5497_2F01)));
+        assert_eq!(range.next(), Some(Eui48::from(0x4D7E_5497_2EFF)));
+        assert_eq!(range.next_back(), Some(Eui48::from(0x4D7E_5497_2F00)));
+        assert_eq!(range.next(), None);
+        assert_eq!(range.next_back(), None);
+        assert_eq!(range.len(), 0);
+    }
+
+    #[test]
+    fn test_eui48_range_bounds() {
+        let last = Eui48::from(0xFFFF_FFFF_FFFF);
+
+        assert_eq!(
+            Eui48::range(last, last).collect::<heapless::Vec<_, 2>>(),
+            [last]
+        );
+        assert_eq!(Eui48::range(last, Eui48::from(0)).next(), None);
+        assert_eq!(
+            Eui48::range(Eui48::from(0), last).size_hint(),
+            (1 << 48, Some(1 << 48))
+        );
+        assert_eq!(Eui48::range(Eui48::from(0), last).len(), 1 << 48);
+    }
+
+    #[test]
+    fn test_eui64_range() {
+        let last = Eui64::from(u64::MAX);
+        let mut range = Eui64::range(Eui64::from(u64::MAX - 1), last);
+
+        assert_eq!(range.next_back(), Some(last));
+        assert_eq!(range.next_back(), Some(Eui64::from(u64::MAX - 1)));
+        assert_eq!(range.next_back(), None);
+        assert_eq!(
+            Eui64::range(Eui64::from(0), last).size_hint(),
+            (usize::MAX, None)
+        );
+        assert_eq!(Eui64::range(Eui64::from(0), last).len(), 1 << 64);
+        assert!(range.is_empty());
+    }
+}
@@ -0,0 +1,82 @@
+extern crate alloc;
+
+use crate::{Eui48, Eui64};
+use alloc::borrow::Cow;
+use schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
+
+const EUI48_PATTERN: &str = "^(?:[0-9A-Fa-f]{12}\
+                             |[0-9A-Fa-f]{2}(?:-[0-9A-Fa-f]{2}){5}\
+                             |[0-9A-Fa-f]{2}(?::[0-9A-Fa-f]{2}){5}\
+                             |[0-9A-Fa-f]{2}(?: [0-9A-Fa-f]{2}){5}\
+                             |[0-9A-Fa-f]{4}(?:\\.[0-9A-Fa-f]{4}){2})$";
+
+const EUI64_PATTERN: &str = "^(?:[0-9A-Fa-f]{16}\
+                             |[0-9A-Fa-f]{2}(?:-[0-9A-Fa-f]{2}){7}\
+                             |[0-9A-Fa-f]{2}(?::[0-9A-Fa-f]{2}){7}\
+                             |[0-9A-Fa-f]{2}(?: [0-9A-Fa-f]{2}){7}\
+                             |[0-9A-Fa-f]{4}(?:\\.[0-9A-Fa-f]{4}){3})$";
+
+impl JsonSchema for Eui48 {
+    fn schema_name() -> Cow<'static, str> {
+        "Eui48".into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        "eui::Eui48".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "string",
+            "description": "EUI-48 address, e.g. 4D-7E-54-97-2E-EF",
+            "pattern": EUI48_PATTERN,
+            "minLength": 12,
+            "maxLength": 17,
+        })
+    }
+}
+
+impl JsonSchema for Eui64 {
+    fn schema_name() -> Cow<'static, str> {
+        "Eui64".into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        "eui::Eui64".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "string",
+            "description": "EUI-64 address, e.g. 4D-7E-54-00-00-97-2E-EF",
+            "pattern": EUI64_PATTERN,
+            "minLength": 16,
+            "maxLength": 23,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{EUI48_PATTERN, EUI64_PATTERN};
+    use crate::{Eui48, Eui64};
+    use schemars::schema_for;
+
+    #[test]
+    fn test_eui48_json_schema() {
+        let schema = schema_for!(Eui48);
+
+        assert_eq!(schema.get("type").unwrap(), "string");
+        assert_eq!(schema.get("pattern").unwrap(), EUI48_PATTERN);
+        assert_eq!(schema.get("minLength").unwrap(), 12);
+        assert_eq!(schema.get("maxLength").unwrap(), 17);
+    }
+
+    #[test]
+    fn test_eui64_json_schema() {
+        let schema = schema_for!(Eui64);
+
+        assert_eq!(schema.get("pattern").unwrap(), EUI64_PATTERN);
+        assert_eq!(schema.get("maxLength").unwrap(), 23);
+    }
+}
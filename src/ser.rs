@@ -1,32 +1,250 @@
-use crate::{Eui48, Eui64};
+use crate::{
+    BareHex, Dotted, Eui48, Eui48Block, Eui48Mask, Eui48Pattern, Eui64, EuiFormat, LowerColon, Oui,
+};
+use serde::ser::SerializeTuple;
 use serde::{Serialize, Serializer};
 
 impl Serialize for Eui48 {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-        serializer.serialize_str(&self.to_string())
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&self.format_with(EuiFormat::CANONICAL))
+        } else {
+            self.0.serialize(serializer)
+        }
     }
 }
 
 impl Serialize for Eui64 {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-        serializer.serialize_str(&self.to_string())
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&self.format_with(EuiFormat::CANONICAL))
+        } else {
+            self.0.serialize(serializer)
+        }
     }
 }
 
+impl Serialize for Oui {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&self.format_with(EuiFormat::CANONICAL))
+        } else {
+            self.0.serialize(serializer)
+        }
+    }
+}
+
+/// Serialized as `base+len` string or `(base, len)` tuple for compact formats.
+impl Serialize for Eui48Block {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.collect_str(self)
+        } else {
+            let mut tuple = serializer.serialize_tuple(2)?;
+            tuple.serialize_element(&self.base())?;
+            tuple.serialize_element(&self.len())?;
+            tuple.end()
+        }
+    }
+}
+
+/// Serialized as `pattern/mask` string or `(pattern, mask)` tuple for compact formats.
+impl Serialize for Eui48Mask {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.collect_str(&self.display_with(EuiFormat::CANONICAL))
+        } else {
+            (self.pattern(), self.mask()).serialize(serializer)
+        }
+    }
+}
+
+/// Serialized as glob-style string or `(pattern, mask)` tuple for compact formats.
+impl Serialize for Eui48Pattern {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.collect_str(self)
+        } else {
+            let mask = self.to_mask();
+            (mask.pattern(), mask.mask()).serialize(serializer)
+        }
+    }
+}
+
+macro_rules! serialize_wrapper {
+    ($name: ident) => {
+        impl Serialize for $name<Eui48> {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(&self.0.format_with(Self::FORMAT))
+            }
+        }
+
+        impl Serialize for $name<Eui64> {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(&self.0.format_with(Self::FORMAT))
+            }
+        }
+    };
+}
+
+serialize_wrapper!(LowerColon);
+serialize_wrapper!(BareHex);
+serialize_wrapper!(Dotted);
+
 #[cfg(test)]
 mod tests {
-    use crate::{Eui48, Eui64};
-    use serde_test::{assert_ser_tokens, Token};
+    use crate::{BareHex, Dotted, Eui48, Eui64, LowerColon};
+    use serde_test::{assert_ser_tokens, Configure, Token};
 
     #[test]
     fn test_eui48_serialize() {
         let eui48 = Eui48::from(85204980412143);
-        assert_ser_tokens(&eui48, &[Token::String("4D-7E-54-97-2E-EF")]);
+        assert_ser_tokens(&eui48.readable(), &[Token::String("4D-7E-54-97-2E-EF")]);
     }
 
     #[test]
     fn test_eui64_serialize() {
         let eui64 = Eui64::from(5583992946972634863);
-        assert_ser_tokens(&eui64, &[Token::String("4D-7E-54-00-00-97-2E-EF")]);
+        assert_ser_tokens(
+            &eui64.readable(),
+            &[Token::String("4D-7E-54-00-00-97-2E-EF")],
+        );
+    }
+
+    #[test]
+    fn test_eui48_serialize_compact() {
+        let eui48 = Eui48::from(85204980412143);
+
+        assert_ser_tokens(
+            &eui48.compact(),
+            &[
+                Token::Tuple { len: 6 },
+                Token::U8(0x4d),
+                Token::U8(0x7e),
+                Token::U8(0x54),
+                Token::U8(0x97),
+                Token::U8(0x2e),
+                Token::U8(0xef),
+                Token::TupleEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn test_eui64_serialize_compact() {
+        let eui64 = Eui64::from(5583992946972634863);
+
+        assert_ser_tokens(
+            &eui64.compact(),
+            &[
+                Token::Tuple { len: 8 },
+                Token::U8(0x4d),
+                Token::U8(0x7e),
+                Token::U8(0x54),
+                Token::U8(0x00),
+                Token::U8(0x00),
+                Token::U8(0x97),
+                Token::U8(0x2e),
+                Token::U8(0xef),
+                Token::TupleEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn test_wrapper_serialize() {
+        let eui48 = Eui48::from(85204980412143);
+        let eui64 = Eui64::from(5583992946972634863);
+
+        assert_ser_tokens(&LowerColon(eui48), &[Token::Str("4d:7e:54:97:2e:ef")]);
+        assert_ser_tokens(&BareHex(eui64), &[Token::Str("4d7e540000972eef")]);
+        assert_ser_tokens(&Dotted(eui48), &[Token::Str("4d7e.5497.2eef")]);
+    }
+
+    #[test]
+    fn test_oui_serialize() {
+        use crate::Oui;
+
+        let oui = Oui::new([0x4d, 0x7e, 0x54]);
+
+        assert_ser_tokens(&oui.readable(), &[Token::String("4D-7E-54")]);
+        assert_ser_tokens(
+            &oui.compact(),
+            &[
+                Token::Tuple { len: 3 },
+                Token::U8(0x4d),
+                Token::U8(0x7e),
+                Token::U8(0x54),
+                Token::TupleEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn test_block_serialize() {
+        use crate::Eui48Block;
+
+        let block = Eui48Block::new(Eui48::from(85204980412143), 16).unwrap();
+
+        assert_ser_tokens(&block.readable(), &[Token::String("4D-7E-54-97-2E-EF+16")]);
+        assert_ser_tokens(
+            &block.compact(),
+            &[
+                Token::Tuple { len: 2 },
+                Token::Tuple { len: 6 },
+                Token::U8(0x4d),
+                Token::U8(0x7e),
+                Token::U8(0x54),
+                Token::U8(0x97),
+                Token::U8(0x2e),
+                Token::U8(0xef),
+                Token::TupleEnd,
+                Token::U64(16),
+                Token::TupleEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn test_mask_serialize() {
+        use crate::Eui48Mask;
+
+        let mask = Eui48Mask::from_prefix(Eui48::from(0x4D7E_5400_0000), 24).unwrap();
+
+        assert_ser_tokens(
+            &mask.readable(),
+            &[Token::String("4D-7E-54-00-00-00/FF-FF-FF-00-00-00")],
+        );
+    }
+
+    #[test]
+    fn test_pattern_serialize() {
+        use crate::Eui48Pattern;
+
+        let pattern = Eui48Pattern::parse("4d-7e-54-*-*-*").unwrap();
+        assert_ser_tokens(&pattern.readable(), &[Token::String("4D:7E:54:*:*:*")]);
+        assert_ser_tokens(
+            &Eui48Pattern::parse("4d-7e-5?-*-*-*").unwrap().compact(),
+            &[
+                Token::Tuple { len: 2 },
+                Token::Tuple { len: 6 },
+                Token::U8(0x4d),
+                Token::U8(0x7e),
+                Token::U8(0x50),
+                Token::U8(0),
+                Token::U8(0),
+                Token::U8(0),
+                Token::TupleEnd,
+                Token::Tuple { len: 6 },
+                Token::U8(0xff),
+                Token::U8(0xff),
+                Token::U8(0xf0),
+                Token::U8(0),
+                Token::U8(0),
+                Token::U8(0),
+                Token::TupleEnd,
+                Token::TupleEnd,
+            ],
+        );
     }
 }
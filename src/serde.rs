@@ -0,0 +1,209 @@
+//! Modules for `#[serde(with = "...")]` choosing textual form of serialized eui.
+//!
+//! Serialization always writes a string in the module's form, even for compact formats.
+//! Deserialization reads a string in any form accepted by `TryFrom<&str>`.
+//!
+//! # Example
+//!
+//! ```rust
+//! # extern crate serde;
+//! use eui::Eui48;
+//! use serde::{Serialize, Serializer};
+//!
+//! struct Interface {
+//!     mac: Eui48,
+//! }
+//!
+//! impl Serialize for Interface {
+//!     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+//!         eui::serde::colon_lower::serialize(&self.mac, serializer)
+//!     }
+//! }
+//! ```
+//!
+//! `Eui48Visitor` and `Eui64Visitor` can be reused inside other visitors, for example to
+//! accept either a mac address or an interface name:
+//!
+//! ```rust
+//! # extern crate serde;
+//! use core::fmt;
+//! use eui::serde::Eui48Visitor;
+//! use eui::Eui48;
+//! use serde::de::{Error, Visitor};
+//!
+//! enum Target<'a> {
+//!     Mac(Eui48),
+//!     Interface(&'a str),
+//! }
+//!
+//! struct TargetVisitor;
+//!
+//! impl<'de> Visitor<'de> for TargetVisitor {
+//!     type Value = Target<'de>;
+//!
+//!     fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+//!         formatter.write_str("mac address or interface name")
+//!     }
+//!
+//!     fn visit_borrowed_str<E: Error>(self, v: &'de str) -> Result<Self::Value, E> {
+//!         match Eui48Visitor.visit_str::<E>(v) {
+//!             Ok(mac) => Ok(Target::Mac(mac)),
+//!             Err(_) => Ok(Target::Interface(v)),
+//!         }
+//!     }
+//! }
+//! ```
+pub use crate::de::{Eui48Visitor, Eui64Visitor};
+use crate::{Eui48, Eui64, EuiFormat};
+use ::serde::{Deserializer, Serializer};
+
+mod private {
+    pub trait Sealed {}
+
+    impl Sealed for crate::Eui48 {}
+    impl Sealed for crate::Eui64 {}
+}
+
+/// Eui types supported by modules in `eui::serde`.
+pub trait SerdeEui: private::Sealed + Sized {
+    #[doc(hidden)]
+    fn serialize_formatted<S: Serializer>(
+        &self,
+        format: EuiFormat,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>;
+
+    #[doc(hidden)]
+    fn deserialize_str<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>;
+}
+
+impl SerdeEui for Eui48 {
+    fn serialize_formatted<S: Serializer>(
+        &self,
+        format: EuiFormat,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.format_with(format))
+    }
+
+    fn deserialize_str<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(Eui48Visitor)
+    }
+}
+
+impl SerdeEui for Eui64 {
+    fn serialize_formatted<S: Serializer>(
+        &self,
+        format: EuiFormat,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.format_with(format))
+    }
+
+    fn deserialize_str<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(Eui64Visitor)
+    }
+}
+
+macro_rules! with_module {
+    ($name:ident, $format:expr, $doc:literal) => {
+        #[doc = $doc]
+        pub mod $name {
+            use super::SerdeEui;
+            use crate::EuiFormat;
+            use ::serde::{Deserializer, Serializer};
+
+            pub fn serialize<T, S>(eui: &T, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                T: SerdeEui,
+                S: Serializer,
+            {
+                eui.serialize_formatted($format, serializer)
+            }
+
+            pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+            where
+                T: SerdeEui,
+                D: Deserializer<'de>,
+            {
+                T::deserialize_str(deserializer)
+            }
+        }
+    };
+}
+
+with_module!(
+    canonical_lower,
+    EuiFormat::CANONICAL_LOWER,
+    "Serializes as `4d-7e-54-97-2e-ef`."
+);
+with_module!(
+    colon,
+    EuiFormat::COLON,
+    "Serializes as `4D:7E:54:97:2E:EF`."
+);
+with_module!(
+    colon_lower,
+    EuiFormat::COLON_LOWER,
+    "Serializes as `4d:7e:54:97:2e:ef`."
+);
+with_module!(bare, EuiFormat::BARE, "Serializes as `4D7E54972EEF`.");
+with_module!(
+    bare_lower,
+    EuiFormat::BARE_LOWER,
+    "Serializes as `4d7e54972eef`."
+);
+with_module!(dotted, EuiFormat::DOTTED, "Serializes as `4d7e.5497.2eef`.");
+
+#[cfg(test)]
+mod tests {
+    use crate::{Eui48, Eui64};
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use serde_test::{assert_tokens, Token};
+
+    #[derive(Debug, PartialEq)]
+    struct ColonLower(Eui48);
+
+    impl Serialize for ColonLower {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            crate::serde::colon_lower::serialize(&self.0, serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for ColonLower {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            crate::serde::colon_lower::deserialize(deserializer).map(ColonLower)
+        }
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Dotted(Eui64);
+
+    impl Serialize for Dotted {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            crate::serde::dotted::serialize(&self.0, serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Dotted {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            crate::serde::dotted::deserialize(deserializer).map(Dotted)
+        }
+    }
+
+    #[test]
+    fn test_eui48_colon_lower() {
+        assert_tokens(
+            &ColonLower(Eui48::from(85204980412143)),
+            &[Token::Str("4d:7e:54:97:2e:ef")],
+        );
+    }
+
+    #[test]
+    fn test_eui64_dotted() {
+        assert_tokens(
+            &Dotted(Eui64::from(5583992946972634863)),
+            &[Token::Str("4d7e.5400.0097.2eef")],
+        );
+    }
+}
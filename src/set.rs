@@ -0,0 +1,200 @@
+use crate::{Eui, Eui48};
+use core::fmt;
+use core::slice;
+
+/// Fixed-capacity set of `Eui48` kept in sorted array, suited for read-mostly allow-lists.
+///
+/// Lookups use binary search, insertion and removal shift following entries.
+///
+/// # Example
+///
+/// ```rust
+/// use eui::{Eui48, EuiSet};
+///
+/// let mut allowed: EuiSet<16> = EuiSet::new();
+/// allowed.insert(Eui48::from(85204980412143)).unwrap();
+///
+/// assert!(allowed.contains(&Eui48::from(85204980412143)));
+/// assert!(!allowed.contains(&Eui48::from(1)));
+/// ```
+#[derive(Clone)]
+pub struct EuiSet<const N: usize> {
+    entries: [Eui48; N],
+    len: usize,
+}
+
+impl<const N: usize> EuiSet<N> {
+    /// Creates empty set.
+    pub const fn new() -> Self {
+        EuiSet {
+            entries: [Eui([0; 6]); N],
+            len: 0,
+        }
+    }
+
+    /// Returns number of euis in set.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if set has no euis.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns maximum number of euis in set.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns euis in ascending order.
+    #[inline]
+    pub fn as_slice(&self) -> &[Eui48] {
+        &self.entries[..self.len]
+    }
+
+    /// Returns `true` if set contains eui.
+    pub fn contains(&self, eui: &Eui48) -> bool {
+        self.as_slice().binary_search(eui).is_ok()
+    }
+
+    /// Adds eui, returning `false` if it was already present.
+    ///
+    /// Eui is given back as error when set is full.
+    pub fn insert(&mut self, eui: Eui48) -> Result<bool, Eui48> {
+        let index = match self.as_slice().binary_search(&eui) {
+            Ok(_) => return Ok(false),
+            Err(index) => index,
+        };
+
+        if self.len == N {
+            return Err(eui);
+        }
+
+        self.entries[index..=self.len].rotate_right(1);
+        self.entries[index] = eui;
+        self.len += 1;
+
+        Ok(true)
+    }
+
+    /// Removes eui, returning `true` if it was present.
+    pub fn remove(&mut self, eui: &Eui48) -> bool {
+        match self.as_slice().binary_search(eui) {
+            Ok(index) => {
+                self.entries[index..self.len].rotate_left(1);
+                self.len -= 1;
+                true
+            }
+            Err(_) => false,
+        }
+    }
+
+    /// Removes all euis.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.len = 0;
+    }
+
+    /// Returns iterator over euis in ascending order.
+    #[inline]
+    pub fn iter(&self) -> slice::Iter<'_, Eui48> {
+        self.as_slice().iter()
+    }
+}
+
+impl<const N: usize> Default for EuiSet<N> {
+    fn default() -> Self {
+        EuiSet::new()
+    }
+}
+
+/// Sets are equal when they hold the same euis, regardless of capacity left behind.
+impl<const N: usize> PartialEq for EuiSet<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<const N: usize> Eq for EuiSet<N> {}
+
+impl<const N: usize> fmt::Debug for EuiSet<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_set().entries(self.as_slice()).finish()
+    }
+}
+
+impl<'a, const N: usize> IntoIterator for &'a EuiSet<N> {
+    type Item = &'a Eui48;
+    type IntoIter = slice::Iter<'a, Eui48>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use crate::{Eui48, EuiSet};
+    use std::format;
+
+    #[test]
+    fn test_set_insert() {
+        let mut set: EuiSet<3> = EuiSet::new();
+
+        assert_eq!(set.insert(Eui48::from(3)), Ok(true));
+        assert_eq!(set.insert(Eui48::from(1)), Ok(true));
+        assert_eq!(set.insert(Eui48::from(3)), Ok(false));
+        assert_eq!(set.insert(Eui48::from(2)), Ok(true));
+        assert_eq!(set.insert(Eui48::from(4)), Err(Eui48::from(4)));
+
+        assert_eq!(
+            set.as_slice(),
+            [Eui48::from(1), Eui48::from(2), Eui48::from(3)]
+        );
+        assert_eq!(set.len(), set.capacity());
+    }
+
+    #[test]
+    fn test_set_remove() {
+        let mut set: EuiSet<4> = EuiSet::new();
+
+        for value in [5, 1, 3] {
+            set.insert(Eui48::from(value)).unwrap();
+        }
+
+        assert!(set.remove(&Eui48::from(3)));
+        assert!(!set.remove(&Eui48::from(3)));
+        assert!(!set.contains(&Eui48::from(3)));
+        assert!(set.contains(&Eui48::from(5)));
+        assert_eq!(set.iter().count(), 2);
+
+        set.clear();
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn test_set_eq_ignores_history() {
+        let mut fresh: EuiSet<4> = EuiSet::new();
+        fresh.insert(Eui48::from(1)).unwrap();
+
+        let mut removed: EuiSet<4> = EuiSet::new();
+        removed.insert(Eui48::from(1)).unwrap();
+        removed.insert(Eui48::from(2)).unwrap();
+        removed.remove(&Eui48::from(2));
+
+        let mut cleared: EuiSet<4> = EuiSet::new();
+        cleared.insert(Eui48::from(3)).unwrap();
+        cleared.clear();
+        cleared.insert(Eui48::from(1)).unwrap();
+
+        assert_eq!(fresh, removed);
+        assert_eq!(fresh, cleared);
+        assert_eq!(format!("{:?}", removed), "{Eui48(00-00-00-00-00-01)}");
+    }
+}
@@ -0,0 +1,74 @@
+use crate::Eui48;
+
+/// Quadrant of locally administered address space defined by IEEE 802c
+/// Structured Local Address Plan.
+#[derive(Eq, PartialEq, Copy, Clone, Hash, Debug)]
+pub enum SlapQuadrant {
+    /// Extended Local Identifier, second digit `A`, assigned within a Company ID.
+    Eli,
+    /// Standard Assigned Identifier, second digit `E`, assigned by a protocol.
+    Sai,
+    /// Administratively Assigned Identifier, second digit `2`, assigned by local administrator.
+    Aai,
+    /// Reserved for future use, second digit `6`.
+    Reserved,
+}
+
+impl SlapQuadrant {
+    const ALL: [SlapQuadrant; 4] = [
+        SlapQuadrant::Eli,
+        SlapQuadrant::Sai,
+        SlapQuadrant::Aai,
+        SlapQuadrant::Reserved,
+    ];
+
+    /// Returns low nibble of the first octet, including local bit and clear multicast bit.
+    pub(crate) fn nibble(self) -> u8 {
+        match self {
+            SlapQuadrant::Eli => 0x0A,
+            SlapQuadrant::Sai => 0x0E,
+            SlapQuadrant::Aai => 0x02,
+            SlapQuadrant::Reserved => 0x06,
+        }
+    }
+}
+
+impl Eui48 {
+    /// Returns SLAP quadrant of locally administered unicast address or `None` for universally
+    /// administered and multicast addresses.
+    pub fn slap_quadrant(&self) -> Option<SlapQuadrant> {
+        let nibble = self.0[0] & 0x0F;
+
+        SlapQuadrant::ALL
+            .iter()
+            .copied()
+            .find(|quadrant| quadrant.nibble() == nibble)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Eui48, SlapQuadrant};
+
+    #[test]
+    fn test_slap_quadrant() {
+        assert_eq!(
+            Eui48::from(0x0A00_0000_0001).slap_quadrant(),
+            Some(SlapQuadrant::Eli)
+        );
+        assert_eq!(
+            Eui48::from(0xFE00_0000_0001).slap_quadrant(),
+            Some(SlapQuadrant::Sai)
+        );
+        assert_eq!(
+            Eui48::from(0x4200_0000_0001).slap_quadrant(),
+            Some(SlapQuadrant::Aai)
+        );
+        assert_eq!(
+            Eui48::from(0x0600_0000_0001).slap_quadrant(),
+            Some(SlapQuadrant::Reserved)
+        );
+        assert_eq!(Eui48::from(85204980412143).slap_quadrant(), None);
+        assert_eq!(Eui48::from(0x0300_0000_0001).slap_quadrant(), None);
+    }
+}
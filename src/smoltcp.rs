@@ -0,0 +1,62 @@
+use crate::{Eui, Eui48};
+use ::smoltcp::wire::{EthernetAddress, HardwareAddress};
+use core::convert::TryFrom;
+
+impl From<EthernetAddress> for Eui48 {
+    fn from(address: EthernetAddress) -> Self {
+        Eui(address.0)
+    }
+}
+
+impl From<Eui48> for EthernetAddress {
+    fn from(eui48: Eui48) -> Self {
+        EthernetAddress(eui48.0)
+    }
+}
+
+impl From<Eui48> for HardwareAddress {
+    fn from(eui48: Eui48) -> Self {
+        HardwareAddress::Ethernet(eui48.into())
+    }
+}
+
+/// Fails with the original address when it is not an ethernet address.
+impl TryFrom<HardwareAddress> for Eui48 {
+    type Error = HardwareAddress;
+
+    fn try_from(address: HardwareAddress) -> Result<Self, Self::Error> {
+        #[allow(unreachable_patterns)]
+        match address {
+            HardwareAddress::Ethernet(address) => Ok(address.into()),
+            address => Err(address),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Eui48;
+    use ::smoltcp::wire::{EthernetAddress, HardwareAddress};
+    use core::convert::TryFrom;
+
+    #[test]
+    fn test_ethernet_address_conversion() {
+        let eui48 = Eui48::from(85204980412143);
+        let address = EthernetAddress([0x4d, 0x7e, 0x54, 0x97, 0x2e, 0xef]);
+
+        assert_eq!(EthernetAddress::from(eui48), address);
+        assert_eq!(Eui48::from(address), eui48);
+    }
+
+    #[test]
+    fn test_hardware_address_conversion() {
+        let eui48 = Eui48::from(85204980412143);
+        let address = HardwareAddress::from(eui48);
+
+        assert_eq!(
+            address,
+            HardwareAddress::Ethernet(EthernetAddress::from(eui48))
+        );
+        assert_eq!(Eui48::try_from(address), Ok(eui48));
+    }
+}
@@ -0,0 +1,84 @@
+//! [`proptest`](https://docs.rs/proptest) strategies for euis and their textual forms.
+extern crate std;
+
+use crate::{Case, Eui48, Eui64, EuiFormat, Separator};
+use ::proptest::arbitrary::{any, Arbitrary, StrategyFor};
+use ::proptest::sample::select;
+use ::proptest::strategy::{Map, Strategy};
+use std::string::String;
+
+impl Arbitrary for Eui48 {
+    type Parameters = ();
+    type Strategy = Map<StrategyFor<[u8; 6]>, fn([u8; 6]) -> Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        any::<[u8; 6]>().prop_map(Eui48::new)
+    }
+}
+
+impl Arbitrary for Eui64 {
+    type Parameters = ();
+    type Strategy = Map<StrategyFor<[u8; 8]>, fn([u8; 8]) -> Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        any::<[u8; 8]>().prop_map(Eui64::new)
+    }
+}
+
+const SEPARATORS: [(Separator, usize); 5] = [
+    (Separator::Dash, 1),
+    (Separator::Colon, 1),
+    (Separator::Space, 1),
+    (Separator::Dot, 2),
+    (Separator::None, 1),
+];
+
+const CASES: [Case; 2] = [Case::Upper, Case::Lower];
+
+/// Formats accepted by `TryFrom<&str>`.
+pub fn parseable_format() -> impl Strategy<Value = EuiFormat> {
+    (select(&SEPARATORS[..]), select(&CASES[..])).prop_map(|((separator, group_size), case)| {
+        EuiFormat {
+            separator,
+            group_size,
+            case,
+        }
+    })
+}
+
+/// Eui48 together with one of its spellings accepted by `TryFrom<&str>`.
+pub fn eui48_string() -> impl Strategy<Value = (Eui48, String)> {
+    (any::<Eui48>(), parseable_format())
+        .prop_map(|(eui48, format)| (eui48, eui48.format_with(format).as_str().into()))
+}
+
+/// Eui64 together with one of its spellings accepted by `TryFrom<&str>`.
+pub fn eui64_string() -> impl Strategy<Value = (Eui64, String)> {
+    (any::<Eui64>(), parseable_format())
+        .prop_map(|(eui64, format)| (eui64, eui64.format_with(format).as_str().into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{eui48_string, eui64_string};
+    use crate::{Eui48, Eui64};
+    use ::proptest::proptest;
+    use core::convert::TryFrom;
+
+    proptest! {
+        #[test]
+        fn test_eui48_string_round_trip((eui48, input) in eui48_string()) {
+            assert_eq!(Eui48::try_from(input.as_str()), Ok(eui48));
+        }
+
+        #[test]
+        fn test_eui64_string_round_trip((eui64, input) in eui64_string()) {
+            assert_eq!(Eui64::try_from(input.as_str()), Ok(eui64));
+        }
+
+        #[test]
+        fn test_eui48_u64_round_trip(eui48: Eui48) {
+            assert_eq!(Eui48::from(u64::from(eui48)), eui48);
+        }
+    }
+}
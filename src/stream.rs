@@ -0,0 +1,201 @@
+use crate::{hex_digit, Eui, Eui48, StringToEuiError};
+
+/// Incremental parser accepting eui48 one byte at a time.
+///
+/// Accepts the same forms as `TryFrom<&str>`. After the last digit is pushed parsed eui is
+/// returned and parser starts over, on error parser starts over as well.
+///
+/// # Example
+///
+/// ```rust
+/// use eui::{Eui48, EuiParser};
+///
+/// let mut parser = EuiParser::new();
+/// let mut parsed = None;
+///
+/// for byte in b"4d:7e:54:97:2e:ef".iter() {
+///     parsed = parser.push(*byte).unwrap();
+/// }
+///
+/// assert_eq!(parsed, Some(Eui48::from(85204980412143)));
+/// ```
+#[derive(Eq, PartialEq, Copy, Clone, Debug, Default)]
+pub struct EuiParser {
+    result: [u8; 6],
+    digits: u8,
+    group_digits: u8,
+    separator: Option<u8>,
+    position: usize,
+}
+
+fn group_len(separator: u8) -> u8 {
+    if separator == b'.' {
+        4
+    } else {
+        2
+    }
+}
+
+impl EuiParser {
+    pub const fn new() -> Self {
+        EuiParser {
+            result: [0; 6],
+            digits: 0,
+            group_digits: 0,
+            separator: None,
+            position: 0,
+        }
+    }
+
+    /// Discards partially parsed input.
+    pub fn reset(&mut self) {
+        *self = EuiParser::new();
+    }
+
+    /// Returns `true` if no bytes were pushed since last reset.
+    pub fn is_empty(&self) -> bool {
+        self.position == 0
+    }
+
+    /// Feeds next byte of input, returns eui once all digits were received.
+    pub fn push(&mut self, byte: u8) -> Result<Option<Eui48>, StringToEuiError> {
+        let result = self.step(byte);
+
+        if matches!(result, Ok(None)) {
+            self.position += 1;
+        } else {
+            self.reset();
+        }
+
+        result
+    }
+
+    fn step(&mut self, byte: u8) -> Result<Option<Eui48>, StringToEuiError> {
+        let value = match hex_digit(byte) {
+            Some(value) => value,
+            None if matches!(byte, b':' | b'-' | b'.' | b' ') => {
+                match self.separator {
+                    Some(separator) if separator != byte => {
+                        return Err(StringToEuiError::OnlyOneSeparatorTypeExpected);
+                    }
+                    _ => {}
+                }
+
+                if self.group_digits != group_len(byte) {
+                    return Err(StringToEuiError::InvalidSeparatorPlace {
+                        position: self.position,
+                    });
+                }
+
+                self.separator = Some(byte);
+                self.group_digits = 0;
+
+                return Ok(None);
+            }
+            None => {
+                return Err(StringToEuiError::InvalidChar {
+                    char: byte as char,
+                    position: self.position,
+                })
+            }
+        };
+
+        if let Some(separator) = self.separator {
+            if self.group_digits == group_len(separator) {
+                return Err(StringToEuiError::InvalidSeparatorPlace {
+                    position: self.position,
+                });
+            }
+        }
+
+        let index = usize::from(self.digits / 2);
+
+        if self.digits % 2 == 0 {
+            self.result[index] = value << 4;
+        } else {
+            self.result[index] |= value;
+        }
+
+        self.digits += 1;
+        self.group_digits += 1;
+
+        if usize::from(self.digits) == self.result.len() * 2 {
+            Ok(Some(Eui(self.result)))
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+/// Wipes partially parsed octets.
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for EuiParser {
+    fn zeroize(&mut self) {
+        self.result.zeroize();
+        self.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Eui48, EuiParser, StringToEuiError};
+
+    fn push_all(parser: &mut EuiParser, input: &[u8]) -> Result<Option<Eui48>, StringToEuiError> {
+        let mut parsed = None;
+
+        for byte in input.iter() {
+            parsed = parser.push(*byte)?;
+        }
+
+        Ok(parsed)
+    }
+
+    #[test]
+    fn test_eui_parser() {
+        let eui48 = Some(Eui48::from(85204980412143));
+        let mut parser = EuiParser::new();
+
+        assert_eq!(push_all(&mut parser, b"4D-7E-54-97-2E-EF"), Ok(eui48));
+        assert_eq!(push_all(&mut parser, b"4d:7e:54:97:2e:ef"), Ok(eui48));
+        assert_eq!(push_all(&mut parser, b"4d7e.5497.2eef"), Ok(eui48));
+        assert_eq!(push_all(&mut parser, b"4d7e54972eef"), Ok(eui48));
+        assert!(parser.is_empty());
+    }
+
+    #[test]
+    fn test_eui_parser_incomplete() {
+        let mut parser = EuiParser::new();
+
+        assert_eq!(push_all(&mut parser, b"4d:7e:54"), Ok(None));
+        assert!(!parser.is_empty());
+
+        parser.reset();
+        assert!(parser.is_empty());
+    }
+
+    #[test]
+    fn test_eui_parser_invalid() {
+        let mut parser = EuiParser::new();
+
+        assert_eq!(
+            push_all(&mut parser, b"4d:7e-54"),
+            Err(StringToEuiError::OnlyOneSeparatorTypeExpected)
+        );
+        assert_eq!(
+            push_all(&mut parser, b"4d7:e"),
+            Err(StringToEuiError::InvalidSeparatorPlace { position: 3 })
+        );
+        assert_eq!(
+            push_all(&mut parser, b"4d:7e5"),
+            Err(StringToEuiError::InvalidSeparatorPlace { position: 5 })
+        );
+        assert_eq!(
+            push_all(&mut parser, b"4d:7g"),
+            Err(StringToEuiError::InvalidChar {
+                char: 'g',
+                position: 4
+            })
+        );
+        assert!(parser.is_empty());
+    }
+}
@@ -0,0 +1,177 @@
+use crate::format::{FormattedEui48, FormattedEui64};
+use crate::{Eui48, Eui64, EuiFormat, Oui, StringToEuiError, LOCAL_BIT, MULTICAST_BIT};
+use core::convert::TryFrom;
+use core::fmt::{Debug, Display};
+use core::hash::Hash;
+use core::ops::Deref;
+
+mod sealed {
+    pub trait Sealed {}
+
+    impl Sealed for crate::Eui48 {}
+    impl Sealed for crate::Eui64 {}
+}
+
+/// Operations common to [`Eui48`] and [`Eui64`], sealed so it can't be implemented outside
+/// of this crate.
+///
+/// # Example
+///
+/// ```rust
+/// use eui::{AnyEui, Eui48, Eui64};
+///
+/// fn vendor<T: AnyEui>(input: &str) -> Option<eui::Oui> {
+///     T::parse(input).ok().filter(|eui| eui.is_universal()).map(|eui| eui.oui())
+/// }
+///
+/// assert!(vendor::<Eui48>("4C-7E-54-97-2E-EF").is_some());
+/// assert!(vendor::<Eui64>("4E-7E-54-00-00-97-2E-EF").is_none());
+/// ```
+pub trait AnyEui:
+    sealed::Sealed
+    + Copy
+    + Eq
+    + Ord
+    + Hash
+    + Default
+    + Debug
+    + Display
+    + Into<u64>
+    + for<'a> TryFrom<&'a str, Error = StringToEuiError>
+{
+    /// Number of octets.
+    const OCTETS: usize;
+
+    /// Formatted string returned by `format_with`.
+    type Formatted: Deref<Target = str> + Display;
+
+    /// Returns octets of eui.
+    fn as_bytes(&self) -> &[u8];
+
+    /// Returns eui in requested format.
+    fn format_with(&self, format: EuiFormat) -> Self::Formatted;
+
+    /// Returns vendor prefix of eui.
+    fn oui(&self) -> Oui;
+
+    /// Parses eui in any supported string form.
+    #[inline]
+    fn parse(input: &str) -> Result<Self, StringToEuiError> {
+        Self::try_from(input)
+    }
+
+    /// Returns `true` if I/G bit is set.
+    #[inline]
+    fn is_multicast(&self) -> bool {
+        self.as_bytes()[0] & MULTICAST_BIT != 0
+    }
+
+    /// Returns `true` if I/G bit is clear.
+    #[inline]
+    fn is_unicast(&self) -> bool {
+        !self.is_multicast()
+    }
+
+    /// Returns `true` if U/L bit is set.
+    #[inline]
+    fn is_local(&self) -> bool {
+        self.as_bytes()[0] & LOCAL_BIT != 0
+    }
+
+    /// Returns `true` if U/L bit is clear.
+    #[inline]
+    fn is_universal(&self) -> bool {
+        !self.is_local()
+    }
+
+    /// Returns `true` if all octets are zero.
+    #[inline]
+    fn is_nil(&self) -> bool {
+        self.as_bytes().iter().all(|&octet| octet == 0)
+    }
+
+    /// Returns `true` if all octets are `0xFF`.
+    #[inline]
+    fn is_broadcast(&self) -> bool {
+        self.as_bytes().iter().all(|&octet| octet == 0xFF)
+    }
+}
+
+impl AnyEui for Eui48 {
+    const OCTETS: usize = 6;
+
+    type Formatted = FormattedEui48;
+
+    #[inline]
+    fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    #[inline]
+    fn format_with(&self, format: EuiFormat) -> FormattedEui48 {
+        Eui48::format_with(self, format)
+    }
+
+    #[inline]
+    fn oui(&self) -> Oui {
+        Eui48::oui(self)
+    }
+}
+
+impl AnyEui for Eui64 {
+    const OCTETS: usize = 8;
+
+    type Formatted = FormattedEui64;
+
+    #[inline]
+    fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    #[inline]
+    fn format_with(&self, format: EuiFormat) -> FormattedEui64 {
+        Eui64::format_with(self, format)
+    }
+
+    #[inline]
+    fn oui(&self) -> Oui {
+        Eui64::oui(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{AnyEui, Eui48, Eui64, EuiFormat};
+
+    fn describe<T: AnyEui>(input: &str) -> (usize, bool, bool, u64) {
+        let eui = T::parse(input).unwrap();
+
+        assert_eq!(eui.as_bytes().len(), T::OCTETS);
+        assert!(eui
+            .format_with(EuiFormat::CANONICAL)
+            .eq_ignore_ascii_case(input));
+
+        (T::OCTETS, eui.is_multicast(), eui.is_local(), eui.into())
+    }
+
+    #[test]
+    fn test_generic_eui() {
+        assert_eq!(
+            describe::<Eui48>("4D-7E-54-97-2E-EF"),
+            (6, true, false, 85204980412143)
+        );
+        assert_eq!(
+            describe::<Eui64>("4e-7e-54-00-00-97-2e-ef"),
+            (8, false, true, 0x4E7E_5400_0097_2EEF)
+        );
+    }
+
+    #[test]
+    fn test_generic_classification() {
+        assert!(Eui48::from(0).is_nil());
+        assert!(Eui48::from(0xFFFF_FFFF_FFFF).is_broadcast());
+        assert!(Eui64::from(u64::MAX).is_broadcast());
+        assert!(Eui64::from(0x0200_0000_0000_0000).is_unicast());
+        assert!(!Eui64::from(0x0200_0000_0000_0000).is_universal());
+    }
+}
@@ -0,0 +1,72 @@
+use crate::{Eui48, Eui64};
+use ufmt::{uDebug, uDisplay, uWrite, Formatter};
+
+impl uDisplay for Eui48 {
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        f.write_str(&self.to_canonical())
+    }
+}
+
+impl uDisplay for Eui64 {
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        f.write_str(&self.to_canonical())
+    }
+}
+
+impl uDebug for Eui48 {
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        f.write_str("Eui48(")?;
+        f.write_str(&self.to_canonical())?;
+        f.write_str(")")
+    }
+}
+
+impl uDebug for Eui64 {
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        f.write_str("Eui64(")?;
+        f.write_str(&self.to_canonical())?;
+        f.write_str(")")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Eui48, Eui64};
+    use core::convert::Infallible;
+    use heapless::String;
+    use ufmt::{uWrite, uwrite};
+
+    struct Buffer(String<64>);
+
+    impl uWrite for Buffer {
+        type Error = Infallible;
+
+        fn write_str(&mut self, s: &str) -> Result<(), Self::Error> {
+            self.0.push_str(s).unwrap();
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_eui48_udisplay() {
+        let mut buffer = Buffer(String::new());
+        let eui48 = Eui48::from(85204980412143);
+
+        uwrite!(buffer, "{} {:?}", eui48, eui48).unwrap();
+
+        assert_eq!(buffer.0, "4D-7E-54-97-2E-EF Eui48(4D-7E-54-97-2E-EF)");
+    }
+
+    #[test]
+    fn test_eui64_udisplay() {
+        let mut buffer = Buffer(String::new());
+        let eui64 = Eui64::from(5583992946972634863);
+
+        uwrite!(buffer, "{} {:?}", eui64, eui64).unwrap();
+
+        assert_eq!(
+            buffer.0,
+            "4D-7E-54-00-00-97-2E-EF Eui64(4D-7E-54-00-00-97-2E-EF)"
+        );
+    }
+}
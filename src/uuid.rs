@@ -0,0 +1,54 @@
+use crate::Eui48;
+use ::uuid::{Builder, Uuid};
+
+impl Eui48 {
+    /// Returns node field of version 1 or version 6 uuid, `None` for other versions.
+    #[inline]
+    pub fn from_uuid_v1_node(uuid: &Uuid) -> Option<Self> {
+        uuid.get_node_id().map(Eui48::new)
+    }
+
+    /// Creates version 1 uuid from count of 100 ns intervals since 1582-10-15, clock sequence
+    /// and eui as node.
+    #[inline]
+    pub fn to_uuid_v1(&self, ticks: u64, counter: u16) -> Uuid {
+        Builder::from_gregorian_timestamp(ticks, counter, &self.0).into_uuid()
+    }
+
+    /// Creates sortable version 6 uuid from count of 100 ns intervals since 1582-10-15,
+    /// clock sequence and eui as node.
+    #[inline]
+    pub fn to_uuid_v6(&self, ticks: u64, counter: u16) -> Uuid {
+        Builder::from_sorted_gregorian_timestamp(ticks, counter, &self.0).into_uuid()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Eui48;
+    use ::uuid::{Uuid, Version};
+
+    #[test]
+    fn test_uuid_v1_node() {
+        let eui48 = Eui48::from(85204980412143);
+        let uuid = eui48.to_uuid_v1(0x1ec_9414_c232_ab00, 0x3fff);
+
+        assert_eq!(uuid.get_version(), Some(Version::Mac));
+        assert_eq!(uuid.as_bytes()[10..], [0x4d, 0x7e, 0x54, 0x97, 0x2e, 0xef]);
+        assert_eq!(Eui48::from_uuid_v1_node(&uuid), Some(eui48));
+    }
+
+    #[test]
+    fn test_uuid_v6_node() {
+        let eui48 = Eui48::from(85204980412143);
+        let uuid = eui48.to_uuid_v6(0x1ec_9414_c232_ab00, 0x3fff);
+
+        assert_eq!(uuid.get_version(), Some(Version::SortMac));
+        assert_eq!(Eui48::from_uuid_v1_node(&uuid), Some(eui48));
+    }
+
+    #[test]
+    fn test_uuid_other_version() {
+        assert_eq!(Eui48::from_uuid_v1_node(&Uuid::nil()), None);
+    }
+}
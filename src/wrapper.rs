@@ -0,0 +1,79 @@
+use crate::{Eui48, Eui64, EuiFormat};
+use core::fmt;
+
+macro_rules! wrapper {
+    ($name: ident, $format: expr, $description: expr) => {
+        #[doc = $description]
+        #[repr(transparent)]
+        #[derive(Eq, PartialEq, Copy, Clone, Hash, Debug)]
+        pub struct $name<T>(pub T);
+
+        impl<T> $name<T> {
+            pub(crate) const FORMAT: EuiFormat = $format;
+
+            /// Returns wrapped eui.
+            pub fn into_inner(self) -> T {
+                self.0
+            }
+        }
+
+        impl fmt::Display for $name<Eui48> {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.pad(&self.0.format_with(Self::FORMAT))
+            }
+        }
+
+        impl fmt::Display for $name<Eui64> {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.pad(&self.0.format_with(Self::FORMAT))
+            }
+        }
+
+        impl From<Eui48> for $name<Eui48> {
+            fn from(eui48: Eui48) -> Self {
+                $name(eui48)
+            }
+        }
+
+        impl From<Eui64> for $name<Eui64> {
+            fn from(eui64: Eui64) -> Self {
+                $name(eui64)
+            }
+        }
+    };
+}
+
+wrapper!(
+    LowerColon,
+    EuiFormat::COLON_LOWER,
+    "Eui displayed and serialized as `4d:7e:54:97:2e:ef`."
+);
+wrapper!(
+    BareHex,
+    EuiFormat::BARE_LOWER,
+    "Eui displayed and serialized as `4d7e54972eef`."
+);
+wrapper!(
+    Dotted,
+    EuiFormat::DOTTED,
+    "Eui displayed and serialized as `4d7e.5497.2eef`."
+);
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use crate::{BareHex, Dotted, Eui48, Eui64, LowerColon};
+    use std::format;
+
+    #[test]
+    fn test_wrapper_display() {
+        let eui48 = Eui48::from(85204980412143);
+        let eui64 = Eui64::from(5583992946972634863);
+
+        assert_eq!(format!("{}", LowerColon(eui48)), "4d:7e:54:97:2e:ef");
+        assert_eq!(format!("{}", BareHex(eui64)), "4d7e540000972eef");
+        assert_eq!(format!("{}", Dotted(eui48)), "4d7e.5497.2eef");
+        assert_eq!(LowerColon::from(eui48).into_inner(), eui48);
+    }
+}
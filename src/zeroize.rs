@@ -0,0 +1,46 @@
+use crate::{Eui, Oui};
+use ::zeroize::Zeroize;
+
+impl<const N: usize> Zeroize for Eui<N> {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl Zeroize for Oui {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Eui48, Eui64, EuiParser, FormattedEui48};
+    use ::zeroize::Zeroize;
+
+    #[test]
+    fn test_zeroize_eui() {
+        let mut eui48 = Eui48::from(85204980412143);
+        let mut eui64 = Eui64::from(5583992946972634863);
+
+        eui48.zeroize();
+        eui64.zeroize();
+
+        assert_eq!(eui48, Eui48::from(0));
+        assert_eq!(eui64, Eui64::from(0));
+    }
+
+    #[test]
+    fn test_zeroize_buffers() {
+        let mut formatted = Eui48::from(85204980412143).to_canonical();
+        formatted.zeroize();
+        assert_eq!(formatted, FormattedEui48::empty());
+
+        let mut parser = EuiParser::new();
+        for byte in b"4d:7e:54".iter() {
+            parser.push(*byte).unwrap();
+        }
+        parser.zeroize();
+        assert_eq!(parser, EuiParser::new());
+    }
+}